@@ -1,6 +1,7 @@
 use std::f64::consts::PI;
 
 use crate::utils::complex_number::{Complex, Cartesian, Polar};
+use crate::utils::bitmap::{rotate_bitmap, RotationMode};
 
 pub fn programming_drill_1_1_1() {
     println!("Solution to the programming drill 1.1.1.");
@@ -52,37 +53,9 @@ pub fn programming_drill_1_3_2() {
     print_matrix(&matrix);
     println!("*****************************************");
 
-    let mut new_matrix = vec![vec![false; 15]; 15];
-
     // Resizing and rotating factor.
-    let factor = Complex::from(Polar(2.0, PI / 4.0));
-
-    // Reposition tiles.
-    for (y, row) in matrix.iter().enumerate() {
-        for (x, elem) in row.iter().enumerate() {
-            // We ignore blank elements because they could overlap (due to
-            // rounding errors) with filled tiles. A more robust algorithm
-            // would effectively prevent this, but that is out of the scope
-            // of this exercise.
-            if !(*elem) {
-                continue;
-            }
-
-            // Compute the new position.
-            let mut c = Complex::new((x as f64) - 7.0, (y as f64) - 7.0);
-            c = c * factor;
-            let Complex { real: r, imaginary: i } = c;
-            let new_x = (r as i64) + 7;
-            let new_y = (i as i64) + 7;
-
-            // Ignore out of bounds.
-            if new_x >= 0 && new_x < (row.len() as i64) {
-                if new_y >= 0 && new_y < (matrix.len() as i64) {
-                    new_matrix[(new_y as usize)][(new_x as usize)] = *elem;
-                }
-            }
-        }
-    }
+    let factor = Complex::new(2.0, 0.0).rotate(PI / 4.0);
+    let new_matrix = rotate_bitmap(&matrix, factor, (7.0, 7.0), RotationMode::Nearest);
 
     // Print the new image.
     print_matrix(&new_matrix);
@@ -91,7 +64,7 @@ pub fn programming_drill_1_3_2() {
 fn print_matrix(matrix: &[Vec<bool>]) {
     matrix.iter().enumerate().for_each(|(i, row)| {
         print!("{}\t", i);
-        row.iter().enumerate().for_each(|(_, col)| {
+        row.iter().for_each(|col| {
             print!("{}", if *col { "■" } else { "□" });
         });
         println!()
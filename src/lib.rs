@@ -0,0 +1,8 @@
+//! Without the default `std` feature the crate is `no_std`, and only the
+//! arithmetic of complex numbers, vectors and matrices is available. Unit
+//! tests always link `std`, so the core's tests also run in that setting.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+pub mod utils;
+#[cfg(feature = "std")]
+pub mod exercises;
@@ -1,7 +1,4 @@
-mod utils;
-mod exercises;
-
-use exercises::{chapter1, chapter2};
+use quantum_computing_studies::exercises::{chapter1, chapter2};
 
 fn main() {
     println!("Hello, quantum!");
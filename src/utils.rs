@@ -1,3 +1,26 @@
 pub mod complex_number;
 pub mod complex_vector;
 pub mod complex_matrix;
+pub mod gaussian_int;
+pub mod bits;
+mod math;
+mod assertions;
+
+#[cfg(feature = "std")]
+pub mod gates;
+#[cfg(feature = "std")]
+pub mod measurement;
+#[cfg(feature = "std")]
+pub mod bitmap;
+#[cfg(feature = "std")]
+pub mod simulation;
+#[cfg(feature = "std")]
+pub mod circuit;
+#[cfg(feature = "std")]
+pub mod state_vector;
+#[cfg(feature = "std")]
+pub mod sparse_state;
+#[cfg(feature = "std")]
+pub mod qubit;
+#[cfg(feature = "std")]
+pub mod expression;
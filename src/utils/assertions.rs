@@ -0,0 +1,61 @@
+//! Assertion macros for tests on complex matrices. They are exported at the
+//! root of the crate.
+
+/// Asserts that a square matrix is unitary, i.e. `M†M = I` up to the given
+/// tolerance. On failure, the message includes the largest entry of `M†M - I`.
+#[macro_export]
+macro_rules! assert_unitary {
+    ($matrix:expr, $epsilon:expr) => {{
+        let matrix = &$matrix;
+        let identity = $crate::utils::complex_matrix::ComplexMatrix::identity();
+        let diff = (&matrix.adjoint() * matrix).max_abs_diff(&identity);
+
+        if diff >= $epsilon {
+            panic!("assertion failed: matrix is not unitary\n  matrix: {}\n  max |M†M - I| entry: {}", matrix, diff);
+        }
+    }};
+}
+
+/// Asserts that a square matrix is Hermitian, i.e. `M = M†` up to the given
+/// tolerance. On failure, the message includes the largest entry of `M - M†`.
+#[macro_export]
+macro_rules! assert_hermitian {
+    ($matrix:expr, $epsilon:expr) => {{
+        let matrix = &$matrix;
+        let diff = matrix.max_abs_diff(&matrix.adjoint());
+
+        if diff >= $epsilon {
+            panic!("assertion failed: matrix is not Hermitian\n  matrix: {}\n  max |M - M†| entry: {}", matrix, diff);
+        }
+    }};
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::utils::complex_number::Complex;
+    use crate::utils::complex_matrix::ComplexMatrix;
+    use crate::utils::gates::{hadamard, pauli_x, pauli_z};
+
+    #[test]
+    fn test_assert_unitary() {
+        assert_unitary!(hadamard(), 1e-12);
+        assert_unitary!(pauli_x(), 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "max |M†M - I| entry: 3")]
+    fn test_assert_unitary_fails() {
+        assert_unitary!(pauli_x() * Complex::new(2.0, 0.0), 1e-12);
+    }
+
+    #[test]
+    fn test_assert_hermitian() {
+        assert_hermitian!(pauli_z(), 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "max |M - M†| entry: 2")]
+    fn test_assert_hermitian_fails() {
+        assert_hermitian!(ComplexMatrix::from_diagonal([Complex::ONE, Complex::I]), 1e-12);
+    }
+}
@@ -0,0 +1,89 @@
+//! Bit manipulation of basis indices of multi-qubit registers.
+//!
+//! Every function follows the convention of the rest of the crate: in a
+//! register of `n_qubits`, qubit 0 is the most significant bit of the index
+//! and qubit `n_qubits - 1` the least significant one. So on three qubits,
+//! index 0b100 is the state |100> with only qubit 0 set.
+//!
+//! Positions must be below `n_qubits`, otherwise the functions panic.
+
+/// The mask with only the bit of qubit `pos` set.
+pub fn mask(pos: usize, n_qubits: usize) -> usize {
+    assert!(pos < n_qubits, "Qubit {} is out of a register of {} qubits.", pos, n_qubits);
+    1 << (n_qubits - 1 - pos)
+}
+
+/// Whether qubit `pos` is set in `index`.
+pub fn bit(index: usize, pos: usize, n_qubits: usize) -> bool {
+    index & mask(pos, n_qubits) != 0
+}
+
+/// The index with qubit `pos` set to `value`.
+pub fn set_bit(index: usize, pos: usize, n_qubits: usize, value: bool) -> usize {
+    if value {
+        index | mask(pos, n_qubits)
+    } else {
+        index & !mask(pos, n_qubits)
+    }
+}
+
+/// The index with qubit `pos` flipped.
+pub fn flip_bit(index: usize, pos: usize, n_qubits: usize) -> usize {
+    index ^ mask(pos, n_qubits)
+}
+
+/// The label of the basis state `index`, as its bits from qubit 0 to qubit
+/// `n_qubits - 1`, e.g. "011".
+#[cfg(feature = "std")]
+pub fn index_to_binary_string(index: usize, n_qubits: usize) -> String {
+    format!("{:0width$b}", index, width = n_qubits)
+}
+
+/// The index of the basis state with the given label, inverse of
+/// `index_to_binary_string`. Returns `None` if it isn't made of 0s and 1s
+/// or doesn't fit in a `usize`.
+pub fn index_from_binary_string(label: &str) -> Option<usize> {
+    if label.is_empty() || !label.chars().all(|c| c == '0' || c == '1') {
+        return None;
+    }
+
+    usize::from_str_radix(label, 2).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit() {
+        assert!(bit(0b100, 0, 3));
+        assert!(!bit(0b100, 2, 3));
+        assert!(bit(0b001, 2, 3));
+
+        assert_eq!(set_bit(0b000, 1, 3, true), 0b010);
+        assert_eq!(set_bit(0b111, 0, 3, false), 0b011);
+        assert_eq!(flip_bit(0b101, 2, 3), 0b100);
+        assert_eq!(flip_bit(flip_bit(0b101, 1, 3), 1, 3), 0b101);
+    }
+
+    #[test]
+    #[should_panic(expected = "Qubit 3 is out of a register of 3 qubits.")]
+    fn test_mask_out_of_register() {
+        mask(3, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_binary_string_round_trip() {
+        assert_eq!(index_to_binary_string(3, 4), "0011");
+        assert_eq!(index_to_binary_string(0, 1), "0");
+
+        for index in 0..16 {
+            assert_eq!(index_from_binary_string(&index_to_binary_string(index, 4)), Some(index));
+        }
+
+        assert_eq!(index_from_binary_string("012"), None);
+        assert_eq!(index_from_binary_string("+1"), None);
+        assert_eq!(index_from_binary_string(""), None);
+    }
+}
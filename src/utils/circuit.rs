@@ -0,0 +1,148 @@
+//! Quantum circuits, as sequences of gates on the qubits of a register.
+//!
+//! Qubits are numbered as in the simulation module, with qubit 0 as the most
+//! significant bit of the basis index.
+
+use crate::utils::complex_vector::ComplexVector;
+use crate::utils::complex_matrix::ComplexMatrix;
+use crate::utils::simulation::{apply_gate, embed_controlled, embed_gate};
+
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(feature = "serde")]
+pub use self::json::CircuitParseError;
+
+/// A single-qubit gate on the `target` qubit, conditioned on the `control`
+/// qubit if there is one.
+struct Step {
+    gate: ComplexMatrix<2, 2>,
+    control: Option<usize>,
+    target: usize,
+}
+
+/// A circuit on a register of qubits, built by appending gates:
+///
+/// ```
+/// # use quantum_computing_studies::utils::circuit::QuantumCircuit;
+/// # use quantum_computing_studies::utils::gates::{hadamard, pauli_x};
+/// let bell = QuantumCircuit::new(2)
+///     .gate(hadamard(), 0)
+///     .controlled(pauli_x(), 0, 1);
+/// ```
+pub struct QuantumCircuit {
+    n_qubits: usize,
+    steps: Vec<Step>,
+}
+
+impl QuantumCircuit {
+    /// Empty circuit on a register of `n_qubits`.
+    pub fn new(n_qubits: usize) -> Self {
+        QuantumCircuit { n_qubits, steps: Vec::new() }
+    }
+
+    /// Number of qubits of the register.
+    pub fn n_qubits(&self) -> usize {
+        self.n_qubits
+    }
+
+    /// Appends `gate` acting on the `target` qubit.
+    pub fn gate(self, gate: ComplexMatrix<2, 2>, target: usize) -> Self {
+        self.push(Step { gate, control: None, target })
+    }
+
+    /// Appends `gate` acting on the `target` qubit, conditioned on the
+    /// `control` qubit.
+    pub fn controlled(self, gate: ComplexMatrix<2, 2>, control: usize, target: usize) -> Self {
+        if control == target {
+            panic!("Control and target must be different qubits.");
+        }
+
+        self.push(Step { gate, control: Some(control), target })
+    }
+
+    /// The unitary matrix of the whole circuit, where N must be 2^n_qubits.
+    /// It takes N×N entries, so prefer `run` for larger registers.
+    pub fn to_unitary<const N: usize>(&self) -> ComplexMatrix<N, N> {
+        self.steps.iter().fold(ComplexMatrix::identity(), |unitary, step| {
+            let m: ComplexMatrix<N, N> = match step.control {
+                Some(control) => embed_controlled(&step.gate, control, step.target, self.n_qubits),
+                None => embed_gate(&step.gate, step.target, self.n_qubits),
+            };
+
+            &m * &unitary
+        })
+    }
+
+    /// Runs the circuit on the `initial` state, where N must be 2^n_qubits.
+    /// Each gate acts directly on the amplitudes it mixes, so the unitary of
+    /// the circuit is never built.
+    pub fn run<const N: usize>(&self, initial: ComplexVector<N>) -> ComplexVector<N> {
+        self.steps.iter().fold(initial, |state, step| {
+            apply_gate(&state, &step.gate, step.control, step.target, self.n_qubits)
+        })
+    }
+
+    fn push(mut self, step: Step) -> Self {
+        let qubit = step.control.unwrap_or(step.target).max(step.target);
+
+        if qubit >= self.n_qubits {
+            panic!("Qubit {} is out of a register of {} qubits.", qubit, self.n_qubits);
+        }
+
+        self.steps.push(step);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::complex_number::Complex;
+    use crate::utils::gates::{hadamard, pauli_x, phase};
+
+    fn bell_circuit() -> QuantumCircuit {
+        QuantumCircuit::new(2).gate(hadamard(), 0).controlled(pauli_x(), 0, 1)
+    }
+
+    #[test]
+    fn test_run_bell() {
+        let amplitude = Complex::new(1.0 / 2f64.sqrt(), 0.0);
+        let phi_plus = ComplexVector([amplitude, Complex::ZERO, Complex::ZERO, amplitude]);
+        let initial = ComplexVector([Complex::ONE, Complex::ZERO, Complex::ZERO, Complex::ZERO]);
+
+        assert!(bell_circuit().run(initial).distance_to(&phi_plus) < 1e-12);
+        assert!(bell_circuit().run(initial).distance_to(&(bell_circuit().to_unitary() * initial)) < 1e-12);
+    }
+
+    #[test]
+    fn test_run_matches_unitary() {
+        let circuit = QuantumCircuit::new(3)
+            .gate(hadamard(), 2)
+            .controlled(phase(0.3), 2, 0)
+            .gate(hadamard(), 1)
+            .controlled(pauli_x(), 1, 2);
+        let initial = ComplexVector::<8>::superposition(&[(1, Complex::ONE), (4, Complex::I), (6, -Complex::ONE)]).unwrap();
+
+        assert!(circuit.run(initial).distance_to(&(circuit.to_unitary() * initial)) < 1e-12);
+    }
+
+    #[test]
+    fn test_run_large_register() {
+        let ghz = (1..6).fold(QuantumCircuit::new(6).gate(hadamard(), 0), |circuit, k| {
+            circuit.controlled(pauli_x(), 0, k)
+        });
+        let mut initial = ComplexVector::<64>::zeros();
+        initial.0[0] = Complex::ONE;
+        let result = ghz.run(initial);
+
+        assert!((result.0[0].abs() - 1.0 / 2f64.sqrt()).abs() < 1e-12);
+        assert!((result.0[63].abs() - 1.0 / 2f64.sqrt()).abs() < 1e-12);
+        assert!((result.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gate_out_of_register() {
+        QuantumCircuit::new(2).gate(hadamard(), 2);
+    }
+}
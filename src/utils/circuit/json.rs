@@ -0,0 +1,159 @@
+//! Loading circuits from a JSON description such as
+//!
+//! ```json
+//! {"qubits": 2, "ops": [{"gate": "H", "target": 0}, {"gate": "CNOT", "control": 0, "target": 1}]}
+//! ```
+//!
+//! Single-qubit gates (H, X, Y, Z, S, T) take a `target`, CNOT takes a
+//! `control` and a `target`, and SWAP takes the two `qubits` to exchange.
+
+use std::error::Error;
+use std::fmt::Display;
+
+use serde::Deserialize;
+
+use crate::utils::gates::{hadamard, pauli_x, pauli_y, pauli_z, s_gate, t_gate};
+use super::QuantumCircuit;
+
+#[derive(Deserialize)]
+struct CircuitDescription {
+    qubits: usize,
+    ops: Vec<OperationDescription>,
+}
+
+#[derive(Deserialize)]
+struct OperationDescription {
+    gate: String,
+    target: Option<usize>,
+    control: Option<usize>,
+    qubits: Option<[usize; 2]>,
+}
+
+/// Error returned when a circuit can't be loaded from JSON.
+#[derive(Debug)]
+pub enum CircuitParseError {
+    /// The JSON is malformed or doesn't describe a circuit.
+    Json(serde_json::Error),
+    /// The gate name is not one of the supported gates.
+    UnknownGate(String),
+    /// The gate lacks a qubit it needs, named by the field.
+    MissingQubit { gate: String, field: &'static str },
+    /// The gate acts on a qubit outside the register, or twice on the same
+    /// qubit.
+    InvalidQubits { gate: String },
+}
+
+impl Display for CircuitParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitParseError::Json(e) => write!(f, "invalid circuit JSON: {}", e),
+            CircuitParseError::UnknownGate(gate) => write!(f, "unknown gate \"{}\"", gate),
+            CircuitParseError::MissingQubit { gate, field } => write!(f, "gate \"{}\" is missing \"{}\"", gate, field),
+            CircuitParseError::InvalidQubits { gate } => write!(f, "gate \"{}\" has invalid qubits", gate),
+        }
+    }
+}
+
+impl Error for CircuitParseError {}
+
+impl From<serde_json::Error> for CircuitParseError {
+    fn from(e: serde_json::Error) -> Self {
+        CircuitParseError::Json(e)
+    }
+}
+
+impl QuantumCircuit {
+    /// Loads a circuit from its JSON description.
+    pub fn from_json(s: &str) -> Result<QuantumCircuit, CircuitParseError> {
+        let description: CircuitDescription = serde_json::from_str(s)?;
+        let n_qubits = description.qubits;
+
+        description.ops.iter().try_fold(QuantumCircuit::new(n_qubits), |circuit, op| {
+            let field = |value: Option<usize>, field| {
+                let qubit = value.ok_or_else(|| CircuitParseError::MissingQubit { gate: op.gate.clone(), field })?;
+
+                if qubit >= n_qubits {
+                    return Err(CircuitParseError::InvalidQubits { gate: op.gate.clone() });
+                }
+
+                Ok(qubit)
+            };
+
+            let gate = match op.gate.as_str() {
+                "H" => hadamard(),
+                "X" => pauli_x(),
+                "Y" => pauli_y(),
+                "Z" => pauli_z(),
+                "S" => s_gate(),
+                "T" => t_gate(),
+                "CNOT" => {
+                    let (control, target) = (field(op.control, "control")?, field(op.target, "target")?);
+
+                    if control == target {
+                        return Err(CircuitParseError::InvalidQubits { gate: op.gate.clone() });
+                    }
+
+                    return Ok(circuit.controlled(pauli_x(), control, target));
+                },
+                "SWAP" => {
+                    let [a, b] = op.qubits.ok_or_else(|| CircuitParseError::MissingQubit { gate: op.gate.clone(), field: "qubits" })?;
+                    let (a, b) = (field(Some(a), "qubits")?, field(Some(b), "qubits")?);
+
+                    if a == b {
+                        return Err(CircuitParseError::InvalidQubits { gate: op.gate.clone() });
+                    }
+
+                    // A swap is three alternating CNOTs.
+                    return Ok(circuit.controlled(pauli_x(), a, b)
+                                     .controlled(pauli_x(), b, a)
+                                     .controlled(pauli_x(), a, b));
+                },
+                _ => return Err(CircuitParseError::UnknownGate(op.gate.clone())),
+            };
+
+            Ok(circuit.gate(gate, field(op.target, "target")?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::complex_number::Complex;
+    use crate::utils::complex_vector::ComplexVector;
+
+    #[test]
+    fn test_from_json_bell() {
+        let json = r#"{"qubits":2,"ops":[{"gate":"H","target":0},{"gate":"CNOT","control":0,"target":1}]}"#;
+        let circuit = QuantumCircuit::from_json(json).unwrap();
+        let amplitude = Complex::new(1.0 / 2f64.sqrt(), 0.0);
+        let phi_plus = ComplexVector([amplitude, Complex::ZERO, Complex::ZERO, amplitude]);
+        let initial = ComplexVector([Complex::ONE, Complex::ZERO, Complex::ZERO, Complex::ZERO]);
+
+        assert_eq!(circuit.n_qubits(), 2);
+        assert!(circuit.run(initial).distance_to(&phi_plus) < 1e-12);
+    }
+
+    #[test]
+    fn test_from_json_swap() {
+        let json = r#"{"qubits":2,"ops":[{"gate":"X","target":0},{"gate":"SWAP","qubits":[0,1]}]}"#;
+        let circuit = QuantumCircuit::from_json(json).unwrap();
+        let initial = ComplexVector([Complex::ONE, Complex::ZERO, Complex::ZERO, Complex::ZERO]);
+
+        assert_eq!(circuit.run(initial), ComplexVector([Complex::ZERO, Complex::ONE, Complex::ZERO, Complex::ZERO]));
+    }
+
+    #[test]
+    fn test_from_json_errors() {
+        let unknown = QuantumCircuit::from_json(r#"{"qubits":1,"ops":[{"gate":"FOO","target":0}]}"#);
+        assert!(matches!(unknown, Err(CircuitParseError::UnknownGate(gate)) if gate == "FOO"));
+
+        let missing = QuantumCircuit::from_json(r#"{"qubits":2,"ops":[{"gate":"CNOT","target":1}]}"#);
+        assert!(matches!(missing, Err(CircuitParseError::MissingQubit { field: "control", .. })));
+
+        let out_of_range = QuantumCircuit::from_json(r#"{"qubits":1,"ops":[{"gate":"H","target":1}]}"#);
+        assert!(matches!(out_of_range, Err(CircuitParseError::InvalidQubits { .. })));
+
+        assert!(matches!(QuantumCircuit::from_json("{"), Err(CircuitParseError::Json(_))));
+    }
+}
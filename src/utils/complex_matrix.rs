@@ -1,10 +1,30 @@
-use std::ops::{Add, Mul, Neg, Index, IndexMut};
-use std::fmt::Display;
+use core::ops::{Add, Sub, Mul, Neg, Index, IndexMut};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::{convert::TryFrom, error::Error};
 
 use crate::utils::complex_number::Complex;
 use crate::utils::complex_vector::ComplexVector;
+use crate::utils::math;
 
-#[derive(Debug, PartialEq)]
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "csv")]
+pub use self::csv::CsvError;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "std")]
+mod formatter;
+#[cfg(feature = "std")]
+pub use self::formatter::MatrixFormatter;
+
+/// Pivots below this are considered zero when row reducing.
+const PIVOT_THRESHOLD: f64 = 1e-12;
+
+/// Eigenvalues closer than this are considered degenerate.
+const DEGENERACY_THRESHOLD: f64 = 1e-12;
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ComplexMatrix<const R: usize, const C: usize>([[Complex; C]; R]);
 
 impl<const R: usize, const C: usize> ComplexMatrix<R, C> {
@@ -12,10 +32,109 @@ impl<const R: usize, const C: usize> ComplexMatrix<R, C> {
         ComplexMatrix(values)
     }
 
+    /// Returns a new matrix resulting of applying `f` to every entry.
+    pub fn map<F: Fn(Complex) -> Complex>(&self, f: F) -> ComplexMatrix<R, C> {
+        ComplexMatrix(self.0.map(|arr| arr.map(&f)))
+    }
+
+    /// Folds every entry into an accumulator, in row-major order.
+    pub fn fold<B, F: Fn(B, Complex) -> B>(&self, init: B, f: F) -> B {
+        self.0.iter().flatten().fold(init, |acc, &x| f(acc, x))
+    }
+
+    /// The sum of all entries.
+    pub fn sum_entries(&self) -> Complex {
+        self.0.iter().flatten().copied().sum()
+    }
+
+    /// The entries of the matrix as a vector, row by row. M must be R*C,
+    /// which is checked on debug builds. Inverse of `ComplexVector::reshape`.
+    pub fn flatten<const M: usize>(self) -> ComplexVector<M> {
+        debug_assert!(M == R * C, "Cannot flatten a {}x{} matrix into a vector of size {}.", R, C, M);
+        let mut v = ComplexVector::zeros();
+
+        for (x, &y) in v.0.iter_mut().zip(self.0.iter().flatten()) {
+            *x = y;
+        }
+
+        v
+    }
+
+    /// The row `i` as a vector. Panics if it is out of range.
+    pub fn row(&self, i: usize) -> ComplexVector<C> {
+        match self.0.get(i) {
+            Some(&row) => ComplexVector(row),
+            None => panic!("Index out of range."),
+        }
+    }
+
+    /// The column `j` as a vector. Panics if it is out of range.
+    pub fn column(&self, j: usize) -> ComplexVector<R> {
+        if j >= C {
+            panic!("Index out of range.");
+        }
+
+        ComplexVector(self.0.map(|row| row[j]))
+    }
+
+    /// Iterator over the rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = ComplexVector<C>> + '_ {
+        self.0.iter().map(|&row| ComplexVector(row))
+    }
+
+    /// Iterator over the columns, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = ComplexVector<R>> + '_ {
+        (0..C).map(move |j| self.column(j))
+    }
+
+    /// Multiplies each row `i` by `factors[i]`, which is the same as
+    /// multiplying by the diagonal matrix of `factors` on the left.
+    pub fn scale_rows(self, factors: [Complex; R]) -> ComplexMatrix<R, C> {
+        let mut m = self;
+
+        for (row, &factor) in m.0.iter_mut().zip(factors.iter()) {
+            for x in row.iter_mut() {
+                *x *= factor;
+            }
+        }
+
+        m
+    }
+
+    /// Multiplies each column `j` by `factors[j]`, which is the same as
+    /// multiplying by the diagonal matrix of `factors` on the right.
+    pub fn scale_columns(self, factors: [Complex; C]) -> ComplexMatrix<R, C> {
+        let mut m = self;
+
+        for row in m.0.iter_mut() {
+            for (x, &factor) in row.iter_mut().zip(factors.iter()) {
+                *x *= factor;
+            }
+        }
+
+        m
+    }
+
+    /// Entrywise (Schur) product, multiplying corresponding entries. Not to be
+    /// confused with the Hadamard gate.
+    pub fn hadamard_product(self, other: Self) -> ComplexMatrix<R, C> {
+        let mut m = self;
+
+        for (x, &y) in m.0.iter_mut().flatten().zip(other.0.iter().flatten()) {
+            *x *= y;
+        }
+
+        m
+    }
+
+    /// Entrywise conjugate, without transposing.
     pub fn conjugate(&self) -> ComplexMatrix<R, C> {
-        let new_elements = self.0.map(|arr| arr.map(|x| x.conjugate()));
+        self.map(Complex::conjugate)
+    }
 
-        ComplexMatrix(new_elements)
+    /// Inverse over addition, by negating each coordinate.
+    pub fn negate(self) -> ComplexMatrix<R, C> {
+        negated_matrix(&self)
     }
 
     pub fn transpose(&self) -> ComplexMatrix<C, R> {
@@ -23,7 +142,7 @@ impl<const R: usize, const C: usize> ComplexMatrix<R, C> {
 
         for j in 0..R {
             for i in 0..C {
-                m[[j, i]] = self[[i, j]];
+                m[[i, j]] = self[[j, i]];
             }
         }
 
@@ -33,12 +152,510 @@ impl<const R: usize, const C: usize> ComplexMatrix<R, C> {
     pub fn conjugate_transpose(&self) -> ComplexMatrix<C, R> {
         self.conjugate().transpose()
     }
+
+    /// The adjoint (dagger) of the matrix. Same as `conjugate_transpose`.
+    pub fn adjoint(&self) -> ComplexMatrix<C, R> {
+        self.conjugate_transpose()
+    }
+
+    /// Whether the matrix has as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        R == C
+    }
+
+    /// The same matrix typed as an N×N square matrix, or `None` if its
+    /// dimensions aren't N×N. This lets generic code call square-only methods:
+    ///
+    /// ```
+    /// # use quantum_computing_studies::utils::complex_matrix::ComplexMatrix;
+    /// # use quantum_computing_studies::utils::complex_number::Complex;
+    /// fn diagonal_or_none<const R: usize, const C: usize>(m: ComplexMatrix<R, C>) -> Option<[Complex; 2]> {
+    ///     m.as_square::<2>().map(|square| square.diagonal())
+    /// }
+    ///
+    /// assert!(diagonal_or_none(ComplexMatrix::<2, 2>::identity()).is_some());
+    /// ```
+    pub fn as_square<const N: usize>(self) -> Option<ComplexMatrix<N, N>> {
+        if R != N || C != N {
+            return None;
+        }
+
+        let mut m = ComplexMatrix::new([[Complex::ZERO; N]; N]);
+
+        for r in 0..N {
+            for c in 0..N {
+                m[[r, c]] = self[[r, c]];
+            }
+        }
+
+        Some(m)
+    }
+
+    /// Kronecker (tensor) product `self ⊗ other`. The dimensions of the result
+    /// must be `R*R2` by `C*C2`, which is checked when called.
+    pub fn kron<const R2: usize, const C2: usize, const R3: usize, const C3: usize>(&self, other: &ComplexMatrix<R2, C2>) -> ComplexMatrix<R3, C3> {
+        if R3 != R * R2 || C3 != C * C2 {
+            panic!("The Kronecker product of a {}x{} and a {}x{} matrix is {}x{}, not {}x{}.",
+                   R, C, R2, C2, R * R2, C * C2, R3, C3);
+        }
+
+        let mut m = ComplexMatrix::new([[Complex::ZERO; C3]; R3]);
+
+        for r in 0..R3 {
+            for c in 0..C3 {
+                m[[r, c]] = self[[r / R2, c / C2]] * other[[r % R2, c % C2]];
+            }
+        }
+
+        m
+    }
+
+    /// Assembles the matrix from four blocks, as
+    ///
+    /// ```text
+    /// | top_left     top_right    |
+    /// | bottom_left  bottom_right |
+    /// ```
+    ///
+    /// The blocks must fill it exactly, i.e. R = A + A2 and C = B + B2, which
+    /// is checked when called.
+    pub fn from_blocks<const A: usize, const B: usize, const A2: usize, const B2: usize>(
+        top_left: ComplexMatrix<A, B>,
+        top_right: ComplexMatrix<A, B2>,
+        bottom_left: ComplexMatrix<A2, B>,
+        bottom_right: ComplexMatrix<A2, B2>,
+    ) -> Self {
+        if R != A + A2 || C != B + B2 {
+            panic!("Blocks of {}x{} and {}x{} make a {}x{} matrix, not {}x{}.", A, B, A2, B2, A + A2, B + B2, R, C);
+        }
+
+        let mut m = ComplexMatrix::new([[Complex::ZERO; C]; R]);
+
+        for r in 0..R {
+            for c in 0..C {
+                m[[r, c]] = match (r < A, c < B) {
+                    (true, true) => top_left[[r, c]],
+                    (true, false) => top_right[[r, c - B]],
+                    (false, true) => bottom_left[[r - A, c]],
+                    (false, false) => bottom_right[[r - A, c - B]],
+                };
+            }
+        }
+
+        m
+    }
+
+    /// The product `I_K ⊗ self`, acting as the matrix on the last factor of a
+    /// larger space. The result must be `K*R` by `K*C`, which is checked when
+    /// called.
+    pub fn tensor_identity_left<const K: usize, const R2: usize, const C2: usize>(self) -> ComplexMatrix<R2, C2> {
+        ComplexMatrix::<K, K>::identity().kron(&self)
+    }
+
+    /// The product `self ⊗ I_K`, acting as the matrix on the first factor of a
+    /// larger space. The result must be `R*K` by `C*K`, which is checked when
+    /// called.
+    pub fn tensor_identity_right<const K: usize, const R2: usize, const C2: usize>(self) -> ComplexMatrix<R2, C2> {
+        self.kron(&ComplexMatrix::<K, K>::identity())
+    }
+
+    /// The product `M†M`, computed in one pass without building the adjoint.
+    /// Entry (j, k) is the inner product of columns j and k.
+    pub fn gram(&self) -> ComplexMatrix<C, C> {
+        let mut m = ComplexMatrix::new([[Complex::ZERO; C]; C]);
+
+        for j in 0..C {
+            for k in 0..C {
+                m[[j, k]] = self.0.iter().map(|row| row[j].conjugate() * row[k]).sum();
+            }
+        }
+
+        m
+    }
+
+    /// The matrix-vector product, borrowing both operands instead of moving
+    /// them as `product_matrix_vector` does.
+    pub fn apply(&self, v: &ComplexVector<C>) -> ComplexVector<R> {
+        self * *v
+    }
+
+    /// Applies the matrix to K states at once, given as the columns of
+    /// `states`. Each column of the result is the matrix applied to the
+    /// corresponding state.
+    ///
+    /// This is just the matrix product, but traversing the matrix once for
+    /// the whole batch is friendlier to the cache than K separate
+    /// matrix-vector products.
+    pub fn apply_columns<const K: usize>(&self, states: ComplexMatrix<C, K>) -> ComplexMatrix<R, K> {
+        self * &states
+    }
+
+    /// Whether every entry is within `epsilon` of the corresponding entry of
+    /// `other`.
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.iter()
+              .flatten()
+              .zip(other.0.iter().flatten())
+              .all(|(&x, &y)| (x - y).abs() < epsilon)
+    }
+
+    /// The largest magnitude among the entrywise differences with `other`.
+    /// Useful to report how far off a failed `approx_eq` is.
+    pub fn max_abs_diff(&self, other: &Self) -> f64 {
+        self.0.iter()
+              .flatten()
+              .zip(other.0.iter().flatten())
+              .map(|(&x, &y)| (x - y).abs())
+              .fold(0.0, f64::max)
+    }
+
+    /// Whether both matrices are equal up to a global phase, i.e. `self` is
+    /// approximately `other` times some unit complex number. Two unitaries
+    /// related this way implement the same operation.
+    pub fn equivalent_up_to_phase(&self, other: &Self, epsilon: f64) -> bool {
+        // Take the largest entry as reference, to keep the phase ratio stable.
+        let reference = self.0.iter()
+                              .flatten()
+                              .zip(other.0.iter().flatten())
+                              .max_by(|(x, _), (y, _)| x.abs().total_cmp(&y.abs()));
+
+        let (&x, &y) = match reference {
+            Some(pair) => pair,
+            None => return true,
+        };
+
+        if x.abs() < epsilon {
+            return self.approx_eq(other, epsilon);
+        }
+
+        if y.abs() < epsilon {
+            return false;
+        }
+
+        let phase = x / y;
+        (phase.abs() - 1.0).abs() < epsilon && self.approx_eq(&other.map(|c| c * phase), epsilon)
+    }
+
+    /// The reduced row echelon form, by Gauss–Jordan elimination with partial
+    /// pivoting, together with the rank (the number of pivots). Columns whose
+    /// best pivot is below a small threshold are treated as already reduced.
+    pub fn rref(self) -> (ComplexMatrix<R, C>, usize) {
+        let mut rows = self.0;
+        let mut rank = 0;
+
+        for c in 0..C {
+            if rank == R {
+                break;
+            }
+
+            let pivot = (rank..R).fold(rank, |best, r| if rows[r][c].abs() > rows[best][c].abs() { r } else { best });
+
+            if rows[pivot][c].abs() < PIVOT_THRESHOLD {
+                continue;
+            }
+
+            rows.swap(pivot, rank);
+            let scale = rows[rank][c];
+            rows[rank] = rows[rank].map(|x| x / scale);
+            let pivot_row = rows[rank];
+
+            for (r, row) in rows.iter_mut().enumerate() {
+                if r == rank {
+                    continue;
+                }
+
+                let factor = row[c];
+
+                for (x, &above) in row.iter_mut().zip(&pivot_row) {
+                    *x = *x - factor * above;
+                }
+            }
+
+            rank += 1;
+        }
+
+        (ComplexMatrix(rows), rank)
+    }
+
+    /// Dimension of the span of the rows (equivalently, of the columns).
+    pub fn rank(self) -> usize {
+        self.rref().1
+    }
 }
 
 impl <const N: usize> ComplexMatrix<N, N> {
+    /// The identity matrix of size N.
+    pub fn identity() -> Self {
+        Self::from_diagonal([Complex::ONE; N])
+    }
+
+    /// Square matrix with the given entries on the diagonal, and zeros elsewhere.
+    pub fn from_diagonal(entries: [Complex; N]) -> Self {
+        let mut m = ComplexMatrix::new([[Complex::ZERO; N]; N]);
+
+        for (i, &entry) in entries.iter().enumerate() {
+            m[[i, i]] = entry;
+        }
+
+        m
+    }
+
+    /// The permutation matrix sending each basis state `j` to `perm[j]`, with
+    /// a one at `(perm[j], j)`. `perm` must be a permutation of `0..N`, which
+    /// is checked on debug builds.
+    pub fn permutation(perm: [usize; N]) -> Self {
+        debug_assert!((0..N).all(|i| perm.contains(&i)), "{:?} is not a permutation of 0..{}.", perm, N);
+        let mut m = ComplexMatrix::new([[Complex::ZERO; N]; N]);
+
+        for (j, &i) in perm.iter().enumerate() {
+            m[[i, j]] = Complex::ONE;
+        }
+
+        m
+    }
+
+    /// The entries on the diagonal of the matrix.
+    pub fn diagonal(&self) -> [Complex; N] {
+        let mut entries = [Complex::ZERO; N];
+
+        for (i, entry) in entries.iter_mut().enumerate() {
+            *entry = self[[i, i]];
+        }
+
+        entries
+    }
+
+    /// The sum of the entries on the diagonal.
+    pub fn trace(&self) -> Complex {
+        self.diagonal().iter().copied().sum()
+    }
+
+    /// The trace of the product, `Tr(AB) = Σ A[i,j]·B[j,i]`, computed in
+    /// O(N²) without building the product.
+    pub fn trace_product(&self, other: &ComplexMatrix<N, N>) -> Complex {
+        (0..N).flat_map(|i| (0..N).map(move |j| (i, j)))
+              .map(|(i, j)| self[[i, j]] * other[[j, i]])
+              .sum()
+    }
+
+    /// The determinant, by Gaussian elimination with partial pivoting. The
+    /// determinant of the 0×0 matrix is 1.
+    pub fn determinant(&self) -> Complex {
+        let mut rows = self.0;
+        let mut det = Complex::ONE;
+
+        for k in 0..N {
+            // Swap up the row with the largest pivot, to limit rounding errors.
+            let pivot = (k..N).fold(k, |best, r| if rows[r][k].abs() > rows[best][k].abs() { r } else { best });
+
+            if rows[pivot][k] == Complex::ZERO {
+                return Complex::ZERO;
+            }
+
+            if pivot != k {
+                rows.swap(pivot, k);
+                det = -det;
+            }
+
+            det *= rows[k][k];
+            let pivot_row = rows[k];
+
+            for row in rows[k + 1..].iter_mut() {
+                let factor = row[k] / pivot_row[k];
+
+                for (x, &above) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                    *x = *x - factor * above;
+                }
+            }
+        }
+
+        det
+    }
+
+    /// Whether the determinant is larger than `epsilon` in magnitude, i.e.
+    /// the matrix has an inverse that isn't numerically degenerate.
+    pub fn is_invertible(&self, epsilon: f64) -> bool {
+        self.determinant().abs() > epsilon
+    }
+
     pub fn is_hermitian(&self) -> bool {
         *self == self.conjugate_transpose()
     }
+
+    /// Whether `MM† = M†M` up to `epsilon` on every entry. Normal matrices
+    /// are exactly those diagonalizable by a unitary, which includes every
+    /// unitary and every Hermitian matrix.
+    pub fn is_normal(&self, epsilon: f64) -> bool {
+        // MM† is the Gram matrix of the adjoint.
+        self.adjoint().gram().approx_eq(&self.gram(), epsilon)
+    }
+
+    /// Whether the matrix is an orthogonal projection, i.e. `P² = P` and
+    /// `P = P†`, both up to `epsilon` on every entry.
+    pub fn is_projection(&self, epsilon: f64) -> bool {
+        (self * self).approx_eq(self, epsilon) && self.approx_eq(&self.adjoint(), epsilon)
+    }
+
+    /// Checks that the matrix is unitary, i.e. `M†M = I` up to `epsilon` on
+    /// every entry. Otherwise, the error points to the largest entry of
+    /// `M†M - I`.
+    pub fn validate_unitary(&self, epsilon: f64) -> Result<(), UnitarityError> {
+        let deviation = self.gram() - ComplexMatrix::identity();
+        let mut error = UnitarityError { max_deviation: 0.0, row: 0 };
+
+        for (r, row) in deviation.0.iter().enumerate() {
+            for entry in row {
+                if entry.abs() > error.max_deviation {
+                    error = UnitarityError { max_deviation: entry.abs(), row: r };
+                }
+            }
+        }
+
+        if error.max_deviation < epsilon {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Whether `M†M = I` up to `epsilon` on every entry.
+    pub fn is_unitary(&self, epsilon: f64) -> bool {
+        self.validate_unitary(epsilon).is_ok()
+    }
+
+    /// Approximates the dominant eigenvalue and its (unit) eigenvector, by
+    /// applying the matrix `iterations` times to `start`, normalizing after
+    /// each step. The eigenvalue is estimated with the Rayleigh quotient ⟨v|Av⟩.
+    ///
+    /// Convergence requires a single eigenvalue of largest magnitude, and a
+    /// `start` with some component along its eigenvector. It slows down as the
+    /// two largest magnitudes get closer. If the iterates vanish (e.g. for a
+    /// nilpotent matrix) the estimate is zero and the last nonzero iterate is
+    /// returned.
+    pub fn power_iteration(&self, iterations: usize, start: ComplexVector<N>) -> (Complex, ComplexVector<N>) {
+        let mut v = start.normalize();
+
+        for _ in 0..iterations {
+            match (self * v).try_normalize() {
+                Some((next, _)) => v = next,
+                None => return (Complex::ZERO, v),
+            }
+        }
+
+        (v * (self * v), v)
+    }
+
+    /// The matrix raised to the power `k`, by repeated squaring. The 0-th
+    /// power is the identity.
+    pub fn pow(&self, k: u32) -> Self {
+        let mut result = Self::identity();
+        let mut square = self.clone();
+        let mut k = k;
+
+        while k > 0 {
+            if k & 1 == 1 {
+                result = &result * &square;
+            }
+
+            square = &square * &square;
+            k >>= 1;
+        }
+
+        result
+    }
+
+    /// The matrix exponential `e^M`, from the first `terms` terms of the
+    /// Taylor series `Σ Mᵏ/k!`.
+    ///
+    /// The error of the truncation is about ‖M‖ⁿ/n! for n terms, so a few
+    /// dozen terms are plenty for matrices of norm up to a few units, but the
+    /// series needs many more terms (and loses precision to cancellation) as
+    /// the norm grows.
+    pub fn exp(&self, terms: usize) -> Self {
+        let mut sum = ComplexMatrix::new([[Complex::ZERO; N]; N]);
+        let mut term = Self::identity();
+
+        // Each term Mᵏ/k! is the previous one times M/k.
+        for k in 1..=terms {
+            sum = &sum + &term;
+            term = &term * self * Complex::new(1.0 / k as f64, 0.0);
+        }
+
+        sum
+    }
+
+    /// The Kronecker product of `gate` with itself `n` times, where N must be
+    /// 2^n. With `n = 0` this is the 1×1 identity.
+    pub fn kron_pow(gate: ComplexMatrix<2, 2>, n: usize) -> Self {
+        if N != 1 << n {
+            panic!("The {}-th Kronecker power of a 2x2 matrix is {}x{}, not {}x{}.", n, 1 << n, 1 << n, N, N);
+        }
+
+        let mut m = Self::identity();
+
+        // Entry (r, c) is the product over every qubit k of the gate entry
+        // indexed by the k-th bits of r and c.
+        for r in 0..N {
+            for c in 0..N {
+                m[[r, c]] = (0..n).map(|k| gate[[(r >> k) & 1, (c >> k) & 1]])
+                                  .fold(Complex::ONE, |acc, x| acc * x);
+            }
+        }
+
+        m
+    }
+}
+
+impl ComplexMatrix<2, 2> {
+    /// Both eigenvalues, as the roots of the characteristic polynomial
+    /// `λ² - tr·λ + det`. The first one takes the principal square root of
+    /// the discriminant with a positive sign.
+    pub fn eigenvalues_2x2(&self) -> (Complex, Complex) {
+        let two = Complex::new(2.0, 0.0);
+        let trace = self.trace();
+        let determinant = self[[0, 0]] * self[[1, 1]] - self[[0, 1]] * self[[1, 0]];
+        let root = (trace * trace - Complex::new(4.0, 0.0) * determinant).sqrt();
+
+        ((trace + root) / two, (trace - root) / two)
+    }
+
+    /// Both eigenvalues, in the order of `eigenvalues_2x2`, each with a
+    /// normalized eigenvector. If the eigenvalues are degenerate, the second
+    /// vector is the one orthogonal to the first, so they always form an
+    /// orthonormal basis. That is an eigenvector too unless the matrix isn't
+    /// diagonalizable, like [[1, 1], [0, 1]].
+    pub fn eigen_2x2(self) -> ((Complex, ComplexVector<2>), (Complex, ComplexVector<2>)) {
+        let (l1, l2) = self.eigenvalues_2x2();
+        let first = self.eigenvector_2x2(l1).unwrap_or(ComplexVector([Complex::ONE, Complex::ZERO]));
+        let orthogonal = ComplexVector([-first.0[1].conjugate(), first.0[0].conjugate()]);
+
+        let second = match self.eigenvector_2x2(l2) {
+            Some(v) if (l1 - l2).abs() > DEGENERACY_THRESHOLD => v,
+            _ => orthogonal,
+        };
+
+        ((l1, first), (l2, second))
+    }
+
+    /// A normalized vector in the kernel of M - λI, taken from whichever of
+    /// its rows is larger. Returns `None` if M - λI is (near) zero, in which
+    /// case every vector is an eigenvector.
+    fn eigenvector_2x2(&self, lambda: Complex) -> Option<ComplexVector<2>> {
+        let from_first_row = ComplexVector([self[[0, 1]], lambda - self[[0, 0]]]);
+        let from_second_row = ComplexVector([lambda - self[[1, 1]], self[[1, 0]]]);
+
+        let candidate = if from_first_row.norm() >= from_second_row.norm() { from_first_row } else { from_second_row };
+        candidate.try_normalize().map(|(unit, _)| unit)
+    }
+
+    /// The trace norm ‖M‖₁, i.e. the sum of the singular values, which are the
+    /// square roots of the eigenvalues of M†M.
+    pub fn trace_norm_2x2(self) -> f64 {
+        let (l1, l2) = self.gram().eigenvalues_2x2();
+
+        // M†M is positive semidefinite, so negative parts are rounding errors.
+        math::sqrt(l1.real.max(0.0)) + math::sqrt(l2.real.max(0.0))
+    }
 }
 
 impl<const N: usize> From<ComplexVector<N>> for ComplexMatrix<N, 1> {
@@ -47,6 +664,82 @@ impl<const N: usize> From<ComplexVector<N>> for ComplexMatrix<N, 1> {
     }
 }
 
+impl<const N: usize> From<ComplexMatrix<N, 1>> for ComplexVector<N> {
+    fn from(ComplexMatrix(rows): ComplexMatrix<N, 1>) -> Self {
+        ComplexVector(rows.map(|[c]| c))
+    }
+}
+
+/// Error returned when a matrix isn't unitary, describing the largest entry of
+/// `M†M - I`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitarityError {
+    /// The magnitude of the largest entry of `M†M - I`.
+    pub max_deviation: f64,
+    /// The row of `M†M - I` holding it. Row `i` compares column `i` of the
+    /// matrix against every column, so it is the column to look at.
+    pub row: usize,
+}
+
+impl Display for UnitarityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "matrix is not unitary: |M†M - I| reaches {} on row {}",
+               self.max_deviation, self.row)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for UnitarityError {}
+
+/// Error returned when the given entries don't match the dimensions of the
+/// matrix being built.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionError {
+    /// The expected (rows, columns).
+    pub expected: (usize, usize),
+    /// The (rows, columns) found. On ragged input, the columns are those of
+    /// the first offending row.
+    pub found: (usize, usize),
+}
+
+impl Display for DimensionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected a {}x{} matrix, found {}x{}",
+               self.expected.0, self.expected.1, self.found.0, self.found.1)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DimensionError {}
+
+/// Support for building matrices from dynamically sized rows, checking the
+/// dimensions.
+#[cfg(feature = "std")]
+impl<const R: usize, const C: usize> TryFrom<Vec<Vec<Complex>>> for ComplexMatrix<R, C> {
+    type Error = DimensionError;
+
+    fn try_from(rows: Vec<Vec<Complex>>) -> Result<Self, Self::Error> {
+        let mut m = ComplexMatrix::new([[Complex::ZERO; C]; R]);
+
+        if rows.len() != R {
+            let columns = rows.first().map_or(0, Vec::len);
+            return Err(DimensionError { expected: (R, C), found: (rows.len(), columns) });
+        }
+
+        for (r, row) in rows.iter().enumerate() {
+            if row.len() != C {
+                return Err(DimensionError { expected: (R, C), found: (R, row.len()) });
+            }
+
+            for (c, &x) in row.iter().enumerate() {
+                m[[r, c]] = x;
+            }
+        }
+
+        Ok(m)
+    }
+}
+
 impl<const R: usize, const C: usize> Index<[usize; 2]> for ComplexMatrix<R, C> {
     type Output = Complex;
 
@@ -71,18 +764,57 @@ impl<const R: usize, const C: usize> IndexMut<[usize; 2]> for ComplexMatrix<R, C
 
         &mut self.0[row][column]
     }
+}
 
+/// Support for indexing with the `m[(row, column)]` spelling.
+impl<const R: usize, const C: usize> Index<(usize, usize)> for ComplexMatrix<R, C> {
+    type Output = Complex;
 
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self[[row, column]]
+    }
+}
+
+impl<const R: usize, const C: usize> IndexMut<(usize, usize)> for ComplexMatrix<R, C> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self[[row, column]]
+    }
 }
 
 impl<const R: usize, const C: usize> Add for ComplexMatrix<R, C> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
+        add_matrices(&self, &rhs)
+    }
+}
+
+/// Support for adding borrowed complex matrices, without moving them.
+impl<'a, const R: usize, const C: usize> Add<&'a ComplexMatrix<R, C>> for &'a ComplexMatrix<R, C> {
+    type Output = ComplexMatrix<R, C>;
+
+    fn add(self, rhs: &'a ComplexMatrix<R, C>) -> Self::Output {
         add_matrices(self, rhs)
     }
 }
 
+impl<const R: usize, const C: usize> Sub for ComplexMatrix<R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        add_matrices(&self, &rhs.negate())
+    }
+}
+
+/// Support for subtracting borrowed complex matrices, without moving them.
+impl<'a, const R: usize, const C: usize> Sub<&'a ComplexMatrix<R, C>> for &'a ComplexMatrix<R, C> {
+    type Output = ComplexMatrix<R, C>;
+
+    fn sub(self, rhs: &'a ComplexMatrix<R, C>) -> Self::Output {
+        add_matrices(self, &negated_matrix(rhs))
+    }
+}
+
 /// Support for scalar product on complex matrices.
 impl<const R: usize, const C: usize> Mul<Complex> for ComplexMatrix<R, C> {
     type Output = Self;
@@ -92,6 +824,15 @@ impl<const R: usize, const C: usize> Mul<Complex> for ComplexMatrix<R, C> {
     }
 }
 
+/// Support for scalar product on complex matrices, with the scalar on the left.
+impl<const R: usize, const C: usize> Mul<ComplexMatrix<R, C>> for Complex {
+    type Output = ComplexMatrix<R, C>;
+
+    fn mul(self, rhs: ComplexMatrix<R, C>) -> Self::Output {
+        product_matrix_scalar(rhs, self)
+    }
+}
+
 /// Support for vector-matrix product.
 impl<const R: usize, const C: usize> Mul<ComplexVector<C>> for ComplexMatrix<R, C> {
     type Output = ComplexVector<R>;
@@ -101,11 +842,29 @@ impl<const R: usize, const C: usize> Mul<ComplexVector<C>> for ComplexMatrix<R,
     }
 }
 
+/// Support for vector-matrix product on a borrowed matrix, without moving it.
+impl<const R: usize, const C: usize> Mul<ComplexVector<C>> for &ComplexMatrix<R, C> {
+    type Output = ComplexVector<R>;
+
+    fn mul(self, rhs: ComplexVector<C>) -> Self::Output {
+        ComplexVector::from(self * &ComplexMatrix::from(rhs))
+    }
+}
+
 /// Support for product on complex matrices.
 impl<const R: usize, const C: usize, const P: usize> Mul<ComplexMatrix<C, P>> for ComplexMatrix<R, C> {
     type Output = ComplexMatrix<R, P>;
 
     fn mul(self, rhs: ComplexMatrix<C, P>) -> Self::Output {
+        product_matrices(&self, &rhs)
+    }
+}
+
+/// Support for product on borrowed complex matrices, without moving them.
+impl<'a, const R: usize, const C: usize, const P: usize> Mul<&'a ComplexMatrix<C, P>> for &'a ComplexMatrix<R, C> {
+    type Output = ComplexMatrix<R, P>;
+
+    fn mul(self, rhs: &'a ComplexMatrix<C, P>) -> Self::Output {
         product_matrices(self, rhs)
     }
 }
@@ -115,13 +874,14 @@ impl<const R: usize, const C: usize> Neg for ComplexMatrix<R, C> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        negated_matrix(self)
+        self.negate()
     }
 }
 
 /// Support for displaying complex matrices.
+#[cfg(feature = "std")]
 impl<const R: usize, const C: usize> Display for ComplexMatrix<R, C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut result_string = String::new();
 
         for r in 0..R {
@@ -142,7 +902,7 @@ impl<const R: usize, const C: usize> Display for ComplexMatrix<R, C> {
 }
 
 /// Coordinate-wise matrix addition.
-fn add_matrices<const R: usize, const C: usize>(matrix1: ComplexMatrix<R, C>, matrix2: ComplexMatrix<R, C>) -> ComplexMatrix<R, C> {
+fn add_matrices<const R: usize, const C: usize>(matrix1: &ComplexMatrix<R, C>, matrix2: &ComplexMatrix<R, C>) -> ComplexMatrix<R, C> {
     let mut result_array: [[Complex; C]; R] = [[Complex::new(0.0, 0.0); C]; R];
 
     for y in 0..C {
@@ -163,14 +923,16 @@ fn product_matrix_scalar<const R: usize, const C: usize>(matrix: ComplexMatrix<R
 
 /// Matrix-Vector product.
 pub fn product_matrix_vector<const R: usize, const C: usize>(matrix: ComplexMatrix<R, C>, vector: ComplexVector<C>) -> ComplexVector<R> {
-    let vec_to_mat = ComplexMatrix::from(vector);
-    let result_matrix = matrix * vec_to_mat;
-    let result_vector = result_matrix.0.map(|row| row[0]);
-    ComplexVector(result_vector)
+    &matrix * vector
+}
+
+/// Trace distance ½‖ρ - σ‖₁ between two single-qubit density matrices.
+pub fn trace_distance(rho: &ComplexMatrix<2, 2>, sigma: &ComplexMatrix<2, 2>) -> f64 {
+    (rho - sigma).trace_norm_2x2() / 2.0
 }
 
 /// Standard complex matrices product.
-fn product_matrices<const R: usize, const C: usize, const P: usize>(m1: ComplexMatrix<R, C>, m2: ComplexMatrix<C, P>) -> ComplexMatrix<R, P> {
+fn product_matrices<const R: usize, const C: usize, const P: usize>(m1: &ComplexMatrix<R, C>, m2: &ComplexMatrix<C, P>) -> ComplexMatrix<R, P> {
     let mut m3 = ComplexMatrix::new([[Complex::new(0.0, 0.0); P]; R]);
 
     for j in 0..R {
@@ -189,7 +951,7 @@ fn product_matrices<const R: usize, const C: usize, const P: usize>(m1: ComplexM
 }
 
 /// Inverse over addition matrix, by negating each coordinate.
-fn negated_matrix<const R: usize, const C: usize>(matrix: ComplexMatrix<R, C>) -> ComplexMatrix<R, C> {
+fn negated_matrix<const R: usize, const C: usize>(matrix: &ComplexMatrix<R, C>) -> ComplexMatrix<R, C> {
     ComplexMatrix(matrix.0.map(|row| row.map(|x| -x)))
 }
 
@@ -269,4 +1031,558 @@ mod tests {
         assert!(m2.is_hermitian());
         assert!(!m3.is_hermitian());
     }
+
+    #[test]
+    fn test_conjugate() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(0.0, -3.0), Complex::new(4.5, 0.0)],
+                                    [Complex::new(-2.0, 1.0), Complex::new(7.0, 7.0), Complex::new(0.0, 1.0)]]);
+        let conj = ComplexMatrix::new([[Complex::new(1.0, -2.0), Complex::new(0.0, 3.0), Complex::new(4.5, 0.0)],
+                                       [Complex::new(-2.0, -1.0), Complex::new(7.0, -7.0), Complex::new(0.0, -1.0)]]);
+
+        assert_eq!(m.conjugate(), conj);
+        assert_eq!(m.conjugate().conjugate(), m);
+        assert_eq!(m.adjoint(), m.conjugate().transpose());
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let d = [Complex::new(1.0, 2.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)];
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::ZERO, Complex::ZERO],
+                                    [Complex::ZERO, Complex::new(0.0, -1.0), Complex::ZERO],
+                                    [Complex::ZERO, Complex::ZERO, Complex::new(3.0, 0.0)]]);
+
+        assert_eq!(ComplexMatrix::from_diagonal(d), m);
+        assert_eq!(ComplexMatrix::from_diagonal(d).diagonal(), d);
+        assert_eq!(ComplexMatrix::from_diagonal([Complex::ONE, Complex::ONE]), ComplexMatrix::identity());
+    }
+
+    #[test]
+    fn test_vector_column_round_trip() {
+        let v = ComplexVector([Complex::new(1.0, -1.0), Complex::new(0.0, 2.0), Complex::new(3.0, 0.0)]);
+        let column = ComplexMatrix::from(v);
+
+        assert_eq!(column[[1, 0]], Complex::new(0.0, 2.0));
+        assert_eq!(ComplexVector::from(column), v);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_from_rows() {
+        let rows = vec![vec![Complex::ONE, Complex::ZERO], vec![Complex::ZERO, Complex::ONE]];
+        assert_eq!(ComplexMatrix::<2, 2>::try_from(rows), Ok(ComplexMatrix::identity()));
+
+        let ragged = vec![vec![Complex::ONE, Complex::ZERO], vec![Complex::ZERO]];
+        let error = DimensionError { expected: (2, 2), found: (2, 1) };
+        assert_eq!(ComplexMatrix::<2, 2>::try_from(ragged), Err(error));
+
+        let short = vec![vec![Complex::ONE, Complex::ZERO]];
+        let error = DimensionError { expected: (2, 2), found: (1, 2) };
+        assert_eq!(ComplexMatrix::<2, 2>::try_from(short), Err(error));
+    }
+
+    #[test]
+    fn test_tuple_index() {
+        let mut m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)],
+                                        [Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)]]);
+
+        assert_eq!(m[(0, 1)], Complex::new(3.0, 4.0));
+        assert_eq!(m[(1, 0)], m[[1, 0]]);
+
+        m[(1, 1)] = Complex::new(0.0, -1.0);
+        assert_eq!(m[[1, 1]], Complex::new(0.0, -1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of range.")]
+    fn test_tuple_index_out_of_range() {
+        let m = ComplexMatrix::<2, 2>::identity();
+        let _ = m[(2, 0)];
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let m1 = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(0.0, 2.0)]]);
+        let m2 = ComplexMatrix::new([[Complex::new(1.0 + 1e-12, 0.0), Complex::new(0.0, 2.0)]]);
+        let m3 = ComplexMatrix::new([[Complex::new(1.1, 0.0), Complex::new(0.0, 2.0)]]);
+
+        assert!(m1.approx_eq(&m2, 1e-9));
+        assert!(!m1.approx_eq(&m3, 1e-9));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_equivalent_up_to_phase() {
+        use crate::utils::gates::{pauli_x, pauli_z};
+
+        let i_z = pauli_z().map(|c| c * Complex::I);
+
+        assert!(pauli_z().equivalent_up_to_phase(&-pauli_z(), 1e-9));
+        assert!(pauli_z().equivalent_up_to_phase(&i_z, 1e-9));
+        assert!(!pauli_z().equivalent_up_to_phase(&pauli_x(), 1e-9));
+        assert!(!pauli_z().equivalent_up_to_phase(&(pauli_z() * Complex::new(2.0, 0.0)), 1e-9));
+    }
+
+    #[test]
+    fn test_borrowed_operators() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)], [Complex::new(3.0, 0.0), Complex::new(4.0, 0.0)]]);
+        let identity = ComplexMatrix::identity();
+        let swap = ComplexMatrix::new([[Complex::ZERO, Complex::ONE], [Complex::ONE, Complex::ZERO]]);
+        let swapped = ComplexMatrix::new([[Complex::new(2.0, 0.0), Complex::new(1.0, 0.0)], [Complex::new(4.0, 0.0), Complex::new(3.0, 0.0)]]);
+        let doubled = ComplexMatrix::new([[Complex::new(2.0, 0.0), Complex::new(4.0, 0.0)], [Complex::new(6.0, 0.0), Complex::new(8.0, 0.0)]]);
+
+        assert_eq!(&m * &identity, m);
+        assert_eq!(&m * &swap, swapped);
+        assert_eq!(&m + &m, doubled);
+    }
+
+    #[test]
+    fn test_is_square() {
+        let square = ComplexMatrix::<2, 2>::identity();
+        let rectangular = ComplexMatrix::new([[Complex::ONE, Complex::ZERO, Complex::I],
+                                              [Complex::ZERO, Complex::ONE, Complex::ZERO]]);
+
+        assert!(square.is_square());
+        assert!(!rectangular.is_square());
+        assert_eq!(square.as_square::<2>(), Some(ComplexMatrix::identity()));
+        assert_eq!(rectangular.as_square::<2>(), None);
+    }
+
+    #[test]
+    fn test_negate() {
+        let entries = [[Complex::new(6.0, -4.0), Complex::new(7.0, 3.0)], [Complex::new(4.2, -8.1), Complex::new(0.0, -3.0)]];
+        assert_eq!(ComplexMatrix::new(entries).negate(), -ComplexMatrix::new(entries));
+    }
+
+    #[test]
+    fn test_sub() {
+        let m = ComplexMatrix::new([[Complex::new(6.0, -4.0), Complex::new(7.0, 3.0)], [Complex::new(4.2, -8.1), Complex::new(0.0, -3.0)]]);
+        let identity = ComplexMatrix::identity();
+
+        assert_eq!(&m - &identity, &m + &-identity.clone());
+        assert_eq!(m.clone() - identity, &m - &ComplexMatrix::identity());
+        assert_eq!(&m - &m, ComplexMatrix::new([[Complex::ZERO; 2]; 2]));
+    }
+
+    #[test]
+    fn test_matrix_product_scalar_left() {
+        let entries = [[Complex::new(0.0, 1.0), Complex::new(2.0, -1.0)], [Complex::new(3.5, 0.0), Complex::new(0.0, 1.0)]];
+        let c = Complex::new(3.0, 2.0);
+
+        assert_eq!(c * ComplexMatrix::new(entries), ComplexMatrix::new(entries) * c);
+    }
+
+    #[test]
+    fn test_kron() {
+        let m1 = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]]);
+        let m2 = ComplexMatrix::new([[Complex::new(0.0, 1.0)], [Complex::new(3.0, 0.0)]]);
+        let m3 = ComplexMatrix::new([[Complex::new(0.0, 1.0), Complex::new(0.0, 2.0)],
+                                     [Complex::new(3.0, 0.0), Complex::new(6.0, 0.0)]]);
+
+        assert_eq!(m1.kron::<2, 1, 2, 2>(&m2), m3);
+        assert_eq!(ComplexMatrix::<2, 2>::identity().kron(&ComplexMatrix::<2, 2>::identity()), ComplexMatrix::<4, 4>::identity());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_kron_pow() {
+        use crate::utils::gates::{hadamard, hadamard_n, pauli_x};
+
+        assert!(ComplexMatrix::<4, 4>::kron_pow(hadamard(), 2).approx_eq(&hadamard_n(2), 1e-12));
+        assert_eq!(ComplexMatrix::<1, 1>::kron_pow(hadamard(), 0), ComplexMatrix::identity());
+
+        let mut zeros = ComplexVector::<8>::zeros();
+        zeros.0[0] = Complex::ONE;
+        let mut ones = ComplexVector::<8>::zeros();
+        ones.0[7] = Complex::ONE;
+        assert_eq!(ComplexMatrix::<8, 8>::kron_pow(pauli_x(), 3) * zeros, ones);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kron_pow_wrong_size() {
+        ComplexMatrix::<4, 4>::kron_pow(ComplexMatrix::identity(), 3);
+    }
+
+    #[test]
+    fn test_max_abs_diff() {
+        let entries = [[Complex::new(1.0, 0.0), Complex::new(0.0, 2.0)], [Complex::new(-1.0, 1.0), Complex::new(3.0, 0.0)]];
+        let mut perturbed = ComplexMatrix::new(entries);
+        perturbed[[1, 0]] += Complex::new(0.0, 0.25);
+        perturbed[[0, 1]] += Complex::new(0.125, 0.0);
+
+        assert_eq!(ComplexMatrix::new(entries).max_abs_diff(&perturbed), 0.25);
+        assert_eq!(perturbed.max_abs_diff(&perturbed), 0.0);
+    }
+
+    #[test]
+    fn test_power_iteration() {
+        let m = ComplexMatrix::from_diagonal([Complex::new(1.0, 0.0), Complex::new(-3.0, 0.0), Complex::new(0.5, 0.0)]);
+        let start = ComplexVector([Complex::ONE, Complex::ONE, Complex::ONE]);
+        let (eigenvalue, eigenvector) = m.power_iteration(100, start);
+
+        assert!((eigenvalue - Complex::new(-3.0, 0.0)).abs() < 1e-9);
+        assert!((eigenvector.0[1].abs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fold() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(3.0, 0.0), Complex::new(0.0, -1.0)],
+                                    [Complex::new(-2.0, 0.5), Complex::new(0.0, 0.0), Complex::new(4.0, 4.0)]]);
+
+        assert_eq!(ComplexMatrix::<3, 3>::identity().sum_entries(), Complex::new(3.0, 0.0));
+        assert_eq!(m.sum_entries(), Complex::new(6.0, 5.5));
+        assert_eq!(m.fold(Complex::ZERO, |acc, x| acc + x), m.sum_entries());
+        assert_eq!(m.fold(Vec::new(), |mut acc, x| { acc.push(x.real); acc }), vec![1.0, 3.0, 0.0, -2.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn test_apply_columns() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(0.0, 2.0)],
+                                    [Complex::new(-1.0, 1.0), Complex::new(3.0, 0.0)],
+                                    [Complex::new(0.5, 0.0), Complex::new(0.0, -1.0)]]);
+        let states = [ComplexVector([Complex::ONE, Complex::ZERO]),
+                      ComplexVector([Complex::new(2.0, -1.0), Complex::I]),
+                      ComplexVector([Complex::new(0.0, 3.0), Complex::new(-4.0, 0.5)])];
+        let stacked = ComplexMatrix::new([[states[0].0[0], states[1].0[0], states[2].0[0]],
+                                          [states[0].0[1], states[1].0[1], states[2].0[1]]]);
+        let result = m.apply_columns(stacked);
+
+        for (k, &state) in states.iter().enumerate() {
+            let ComplexVector(expected) = &m * state;
+
+            for (r, &x) in expected.iter().enumerate() {
+                assert_eq!(result[[r, k]], x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(5.0, 0.0)],
+                                    [Complex::new(0.0, 7.0), Complex::new(-3.0, 1.0)]]);
+
+        assert_eq!(m.trace(), Complex::new(-2.0, 3.0));
+        assert_eq!(ComplexMatrix::<3, 3>::identity().trace(), Complex::new(3.0, 0.0));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_eigenvalues_2x2() {
+        use crate::utils::gates::pauli_y;
+
+        let (l1, l2) = pauli_y().eigenvalues_2x2();
+        assert!((l1 - Complex::ONE).abs() < 1e-12);
+        assert!((l2 + Complex::ONE).abs() < 1e-12);
+
+        let (l1, l2) = ComplexMatrix::from_diagonal([Complex::I, Complex::new(3.0, 0.0)]).eigenvalues_2x2();
+        assert!((l1 - Complex::new(3.0, 0.0)).abs() < 1e-12);
+        assert!((l2 - Complex::I).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_eigen_2x2() {
+        use crate::utils::gates::pauli_x;
+
+        let amplitude = Complex::new(1.0 / 2f64.sqrt(), 0.0);
+        let plus = ComplexVector([amplitude, amplitude]);
+        let minus = ComplexVector([amplitude, -amplitude]);
+
+        let ((l1, v1), (l2, v2)) = pauli_x().eigen_2x2();
+        assert!((l1 - Complex::ONE).abs() < 1e-12);
+        assert!((l2 + Complex::ONE).abs() < 1e-12);
+        assert!(((v1 * plus).abs() - 1.0).abs() < 1e-12);
+        assert!(((v2 * minus).abs() - 1.0).abs() < 1e-12);
+
+        let m = ComplexMatrix::new([[Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+                                    [Complex::new(0.0, 3.0), Complex::new(-1.0, 0.0)]]);
+        let ((l1, v1), (l2, v2)) = m.clone().eigen_2x2();
+        assert!((&m * v1).distance_to(&(v1 * l1)) < 1e-12);
+        assert!((&m * v2).distance_to(&(v2 * l2)) < 1e-12);
+    }
+
+    #[test]
+    fn test_eigen_2x2_degenerate() {
+        use crate::utils::complex_vector::is_orthonormal_basis;
+
+        let m = ComplexMatrix::<2, 2>::identity() * Complex::new(3.0, 0.0);
+        let ((l1, v1), (l2, v2)) = m.eigen_2x2();
+
+        assert_eq!(l1, l2);
+        assert!(is_orthonormal_basis(&[v1, v2], 1e-12));
+    }
+
+    #[test]
+    fn test_trace_distance() {
+        let zero = ComplexMatrix::from_diagonal([Complex::ONE, Complex::ZERO]);
+        let one = ComplexMatrix::from_diagonal([Complex::ZERO, Complex::ONE]);
+        let plus = ComplexMatrix::new([[Complex::new(0.5, 0.0); 2]; 2]);
+
+        assert!((trace_distance(&zero, &one) - 1.0).abs() < 1e-12);
+        assert!(trace_distance(&zero, &zero).abs() < 1e-12);
+        assert!((trace_distance(&zero, &plus) - 1.0 / 2f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_validate_unitary() {
+        use crate::utils::gates::hadamard;
+
+        assert_eq!(hadamard().validate_unitary(1e-12), Ok(()));
+        assert!(hadamard().is_unitary(1e-12));
+
+        // The second column has norm 2.
+        let unnormalized = ComplexMatrix::from_diagonal([Complex::ONE, Complex::new(0.0, 2.0)]);
+        let error = unnormalized.validate_unitary(1e-12).unwrap_err();
+        assert_eq!(error, UnitarityError { max_deviation: 3.0, row: 1 });
+        assert!(!unnormalized.is_unitary(1e-12));
+
+        // Both columns are unit vectors, but ⟨c0|c1⟩ = 1/√2.
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let skewed = ComplexMatrix::new([[Complex::ONE, Complex::new(s, 0.0)],
+                                         [Complex::ZERO, Complex::new(s, 0.0)]]);
+        let error = skewed.validate_unitary(1e-12).unwrap_err();
+        assert!((error.max_deviation - s).abs() < 1e-12);
+        assert_eq!(error.row, 0);
+    }
+
+    #[test]
+    fn test_hadamard_product() {
+        let m1 = ComplexMatrix::new([[Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)],
+                                     [Complex::new(0.0, 3.0), Complex::new(-1.0, 0.0)]]);
+        let m2 = ComplexMatrix::new([[Complex::new(1.0, -1.0), Complex::new(0.0, 1.0)],
+                                     [Complex::new(0.0, 3.0), Complex::new(5.0, 2.0)]]);
+        let expected = ComplexMatrix::new([[Complex::new(2.0, 0.0), Complex::new(0.0, 2.0)],
+                                           [Complex::new(-9.0, 0.0), Complex::new(-5.0, -2.0)]]);
+
+        assert_eq!(m1.hadamard_product(m2), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tensor_identity() {
+        use crate::utils::gates::{hadamard, pauli_x};
+
+        let identity = ComplexMatrix::<2, 2>::identity();
+
+        assert_eq!(pauli_x().tensor_identity_left::<2, 4, 4>(), identity.kron(&pauli_x()));
+        assert_eq!(hadamard().tensor_identity_right::<2, 4, 4>(), hadamard().kron(&identity));
+
+        let column = ComplexMatrix::new([[Complex::ONE], [Complex::I]]);
+        assert_eq!(column.tensor_identity_right::<2, 4, 2>(),
+                   ComplexMatrix::new([[Complex::ONE, Complex::ZERO], [Complex::ZERO, Complex::ONE],
+                                       [Complex::I, Complex::ZERO], [Complex::ZERO, Complex::I]]));
+    }
+
+    #[test]
+    fn test_determinant() {
+        let m = ComplexMatrix::new([[Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)],
+                                    [Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(3.0, 0.0)],
+                                    [Complex::new(1.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]]);
+
+        // Expanding along the second row: -3 * (2*1 - i*1).
+        assert!((m.determinant() - Complex::new(-6.0, 3.0)).abs() < 1e-12);
+        assert_eq!(ComplexMatrix::<3, 3>::identity().determinant(), Complex::ONE);
+        assert_eq!(ComplexMatrix::new([[Complex::ONE; 2]; 2]).determinant(), Complex::ZERO);
+    }
+
+    #[test]
+    fn test_rref_full_rank() {
+        let m = ComplexMatrix::new([[Complex::new(0.0, 0.0), Complex::new(2.0, 0.0), Complex::new(4.0, 0.0)],
+                                    [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)]]);
+        let expected = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, -2.0)],
+                                           [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]]);
+
+        let (reduced, rank) = m.rref();
+        assert!(reduced.approx_eq(&expected, 1e-12));
+        assert_eq!(rank, 2);
+        assert_eq!(ComplexMatrix::<3, 3>::identity().rank(), 3);
+    }
+
+    #[test]
+    fn test_rref_rank_deficient() {
+        // The third row is the first plus i times the second.
+        let m = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(0.0, 0.0)],
+                                    [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)],
+                                    [Complex::new(1.0, 0.0), Complex::new(2.0, 1.0), Complex::new(0.0, 1.0)]]);
+        let expected = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(-2.0, 0.0)],
+                                           [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)],
+                                           [Complex::ZERO; 3]]);
+
+        let (reduced, rank) = m.rref();
+        assert!(reduced.approx_eq(&expected, 1e-12));
+        assert_eq!(rank, 2);
+        assert_eq!(ComplexMatrix::new([[Complex::ZERO; 2]; 3]).rank(), 0);
+    }
+
+    #[test]
+    fn test_apply() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)],
+                                    [Complex::new(0.5, 0.0), Complex::new(2.0, 2.0), Complex::new(0.0, 1.0)]]);
+        let v = ComplexVector([Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(-2.0, 3.0)]);
+
+        assert_eq!(m.apply(&v), product_matrix_vector(m.clone(), v));
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)],
+                                    [Complex::new(0.0, 4.0), Complex::new(0.0, 5.0), Complex::new(0.0, 6.0)]]);
+
+        let rows: Vec<_> = m.rows().collect();
+        assert_eq!(rows.len(), 2);
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(*row, m.row(i));
+        }
+
+        let columns: Vec<_> = m.columns().collect();
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[1], ComplexVector([Complex::new(2.0, 0.0), Complex::new(0.0, 5.0)]));
+        assert_eq!(m.transpose().row(2), m.column(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of range.")]
+    fn test_column_out_of_range() {
+        ComplexMatrix::<2, 2>::identity().column(2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_pow() {
+        use crate::utils::gates::{pauli_x, t_gate, pauli_z};
+
+        assert_eq!(pauli_x().pow(0), ComplexMatrix::identity());
+        assert_eq!(pauli_x().pow(2), ComplexMatrix::identity());
+        assert_eq!(pauli_x().pow(3), pauli_x());
+        assert!(t_gate().pow(4).approx_eq(&pauli_z(), 1e-12));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_exp() {
+        use crate::utils::gates::pauli_z;
+
+        let zero = ComplexMatrix::new([[Complex::ZERO; 3]; 3]);
+        assert_eq!(zero.exp(10), ComplexMatrix::identity());
+
+        let generator = pauli_z() * Complex::new(0.0, -std::f64::consts::FRAC_PI_2);
+        let expected = ComplexMatrix::from_diagonal([Complex::new(0.0, -1.0), Complex::I]);
+        assert!(generator.exp(30).approx_eq(&expected, 1e-12));
+        assert!(generator.exp(30).equivalent_up_to_phase(&pauli_z(), 1e-12));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_permutation() {
+        use crate::utils::gates::{controlled, pauli_x};
+
+        assert_eq!(ComplexMatrix::permutation([1, 0]), pauli_x());
+        assert_eq!(ComplexMatrix::permutation([0, 1, 3, 2]), controlled(pauli_x()));
+        assert_eq!(ComplexMatrix::permutation([0, 1, 2]), ComplexMatrix::identity());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is not a permutation")]
+    fn test_permutation_invalid() {
+        ComplexMatrix::permutation([0, 0, 2]);
+    }
+
+    #[test]
+    fn test_gram() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)],
+                                    [Complex::new(0.5, 0.0), Complex::new(2.0, 2.0)],
+                                    [Complex::new(-3.0, 1.0), Complex::new(0.0, 1.0)]]);
+        let gram = m.gram();
+
+        assert!(gram.approx_eq(&(&m.adjoint() * &m), 1e-12));
+        assert!(gram.is_hermitian());
+    }
+
+    #[test]
+    fn test_is_projection() {
+        let projector_zero = ComplexMatrix::from_diagonal([Complex::ONE, Complex::ZERO]);
+        let half_identity = ComplexMatrix::<2, 2>::identity() * Complex::new(0.5, 0.0);
+        // Idempotent, but projecting along a non-orthogonal direction.
+        let oblique = ComplexMatrix::new([[Complex::ONE, Complex::ONE], [Complex::ZERO, Complex::ZERO]]);
+
+        assert!(projector_zero.is_projection(1e-12));
+        assert!(!half_identity.is_projection(1e-12));
+        assert_eq!(&oblique * &oblique, oblique);
+        assert!(!oblique.is_projection(1e-12));
+    }
+
+    #[test]
+    fn test_scale_rows_columns() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)],
+                                    [Complex::new(0.5, 0.0), Complex::new(2.0, 2.0), Complex::new(0.0, 1.0)]]);
+        let row_factors = [Complex::I, Complex::new(2.0, -1.0)];
+        let column_factors = [Complex::new(-1.0, 0.0), Complex::new(0.5, 0.5), Complex::I];
+
+        assert_eq!(m.clone().scale_rows(row_factors), ComplexMatrix::from_diagonal(row_factors) * m.clone());
+        assert_eq!(m.clone().scale_columns(column_factors), &m * &ComplexMatrix::from_diagonal(column_factors));
+    }
+
+    #[test]
+    fn test_is_invertible() {
+        let rank_deficient = ComplexMatrix::new([[Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0)],
+                                                 [Complex::new(2.0, 2.0), Complex::new(4.0, 0.0), Complex::new(0.0, 2.0)],
+                                                 [Complex::new(0.0, 1.0), Complex::new(1.0, 0.0), Complex::new(3.0, 0.0)]]);
+
+        assert!(ComplexMatrix::<3, 3>::identity().is_invertible(1e-12));
+        assert!(!rank_deficient.is_invertible(1e-12));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_blocks() {
+        use crate::utils::gates::pauli_x;
+
+        let one = ComplexMatrix::new([[Complex::ONE]]);
+        let zero = ComplexMatrix::new([[Complex::ZERO]]);
+        assert_eq!(ComplexMatrix::<2, 2>::from_blocks(one.clone(), zero.clone(), zero, one), ComplexMatrix::identity());
+
+        let zeros = ComplexMatrix::new([[Complex::ZERO; 2]; 2]);
+        let cnot = ComplexMatrix::<4, 4>::from_blocks(ComplexMatrix::identity(), zeros.clone(), zeros, pauli_x());
+        assert_eq!(cnot, ComplexMatrix::permutation([0, 1, 3, 2]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_blocks_mismatch() {
+        ComplexMatrix::<3, 2>::from_blocks(ComplexMatrix::<1, 1>::identity(), ComplexMatrix::<1, 1>::identity(),
+                                           ComplexMatrix::<1, 1>::identity(), ComplexMatrix::<1, 1>::identity());
+    }
+
+    #[test]
+    fn test_trace_product() {
+        let a = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)],
+                                    [Complex::new(0.5, 0.0), Complex::new(2.0, 2.0), Complex::new(0.0, 1.0)],
+                                    [Complex::new(-1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, -1.0)]]);
+        let b = ComplexMatrix::new([[Complex::new(0.0, 1.0), Complex::new(2.0, 0.0), Complex::new(1.0, 1.0)],
+                                    [Complex::new(-3.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+                                    [Complex::new(0.5, 0.5), Complex::new(0.0, -2.0), Complex::new(4.0, 0.0)]]);
+
+        assert!((a.trace_product(&b) - (&a * &b).trace()).abs() < 1e-12);
+        assert!((a.trace_product(&b) - b.trace_product(&a)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_is_normal() {
+        use crate::utils::gates::{hadamard, pauli_y, random_unitary};
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(13);
+        let hermitian = ComplexMatrix::new([[Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+                                            [Complex::new(1.0, 1.0), Complex::new(-3.0, 0.0)]]);
+        let shear = ComplexMatrix::new([[Complex::ONE, Complex::ONE], [Complex::ZERO, Complex::ONE]]);
+
+        assert!(hadamard().is_normal(1e-12));
+        assert!(pauli_y().is_normal(1e-12));
+        assert!(random_unitary::<4>(&mut rng).is_normal(1e-9));
+        assert!(hermitian.is_normal(1e-12));
+        assert!(!shear.is_normal(1e-12));
+    }
 }
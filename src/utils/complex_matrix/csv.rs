@@ -0,0 +1,120 @@
+//! CSV import and export for complex matrices. Every cell holds a complex
+//! number in the same `a+bi` form used for displaying them.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::Display;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use crate::utils::complex_number::{Complex, ParseComplexError};
+use super::{ComplexMatrix, DimensionError};
+
+/// Error returned when a matrix can't be read from CSV.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The reader failed.
+    Io(io::Error),
+    /// A cell is not a valid complex number.
+    Parse(ParseComplexError),
+    /// The CSV doesn't have the dimensions of the matrix.
+    Dimension(DimensionError),
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Io(e) => write!(f, "could not read CSV: {}", e),
+            CsvError::Parse(e) => write!(f, "could not parse CSV cell: {}", e),
+            CsvError::Dimension(e) => write!(f, "wrong CSV dimensions: {}", e),
+        }
+    }
+}
+
+impl Error for CsvError {}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> Self {
+        CsvError::Io(e)
+    }
+}
+
+impl From<ParseComplexError> for CsvError {
+    fn from(e: ParseComplexError) -> Self {
+        CsvError::Parse(e)
+    }
+}
+
+impl From<DimensionError> for CsvError {
+    fn from(e: DimensionError) -> Self {
+        CsvError::Dimension(e)
+    }
+}
+
+impl<const R: usize, const C: usize> ComplexMatrix<R, C> {
+    /// Writes the matrix as CSV, one row per line.
+    pub fn to_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        for row in self.0.iter() {
+            let cells: Vec<String> = row.iter().map(|c| c.to_string()).collect();
+            writeln!(writer, "{}", cells.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a matrix written as CSV, one row per line. Blank lines are
+    /// ignored.
+    pub fn from_csv(reader: impl Read) -> Result<Self, CsvError> {
+        let mut rows = Vec::new();
+
+        for line in BufReader::new(reader).lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let row = line.split(',')
+                          .map(str::parse)
+                          .collect::<Result<Vec<Complex>, _>>()?;
+            rows.push(row);
+        }
+
+        Ok(ComplexMatrix::try_from(rows)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_round_trip() {
+        let m = ComplexMatrix::new([[Complex::new(1.5, -2.0), Complex::new(0.0, 1.0)],
+                                    [Complex::new(-3.25, 0.0), Complex::new(4.0, 0.1)]]);
+        let mut buffer = Vec::new();
+        m.to_csv(&mut buffer).unwrap();
+
+        assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "1.5-2i,0+1i\n-3.25+0i,4+0.1i\n");
+        assert_eq!(ComplexMatrix::<2, 2>::from_csv(buffer.as_slice()).unwrap(), m);
+    }
+
+    #[test]
+    fn test_csv_round_trip_conjugated() {
+        // Conjugating the real entries gives imaginary parts of -0.
+        let m = ComplexMatrix::<2, 2>::identity().adjoint();
+        let mut buffer = Vec::new();
+        m.to_csv(&mut buffer).unwrap();
+
+        assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "1-0i,0-0i\n0-0i,1-0i\n");
+        assert_eq!(ComplexMatrix::<2, 2>::from_csv(buffer.as_slice()).unwrap(), m);
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        let wrong_size = ComplexMatrix::<2, 2>::from_csv("1+0i,0+0i\n".as_bytes());
+        assert!(matches!(wrong_size, Err(CsvError::Dimension(_))));
+
+        let wrong_cell = ComplexMatrix::<1, 2>::from_csv("1+0i,zero\n".as_bytes());
+        assert!(matches!(wrong_cell, Err(CsvError::Parse(_))));
+    }
+}
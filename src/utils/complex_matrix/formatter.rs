@@ -0,0 +1,93 @@
+//! Configurable formatting of complex matrices, for output styles other than
+//! the one of `Display`.
+
+use crate::utils::complex_number::Complex;
+use super::ComplexMatrix;
+
+/// Formatting options for complex matrices. Each entry is shown as `a+bi`
+/// with `precision` decimals, rows are enclosed in `open` and `close`, and
+/// `grid` puts every row on its own line.
+///
+/// ```
+/// use quantum_computing_studies::utils::complex_matrix::{ComplexMatrix, MatrixFormatter};
+///
+/// let m = ComplexMatrix::<2, 2>::identity();
+/// let formatter = MatrixFormatter::default().precision(1).brackets('{', '}');
+///
+/// assert_eq!(formatter.format(&m), "{{1.0+0.0i, 0.0+0.0i}, {0.0+0.0i, 1.0+0.0i}}");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MatrixFormatter {
+    /// Decimals of the real and imaginary parts.
+    pub precision: usize,
+    /// Whether each row goes on its own line.
+    pub grid: bool,
+    /// Opening bracket of the matrix and of each row.
+    pub open: char,
+    /// Closing bracket of the matrix and of each row.
+    pub close: char,
+}
+
+impl Default for MatrixFormatter {
+    /// Three decimals on a single line, with square brackets.
+    fn default() -> Self {
+        MatrixFormatter { precision: 3, grid: false, open: '[', close: ']' }
+    }
+}
+
+impl MatrixFormatter {
+    /// Sets the decimals of the real and imaginary parts.
+    pub fn precision(self, precision: usize) -> Self {
+        MatrixFormatter { precision, ..self }
+    }
+
+    /// Sets whether each row goes on its own line.
+    pub fn grid(self, grid: bool) -> Self {
+        MatrixFormatter { grid, ..self }
+    }
+
+    /// Sets the brackets of the matrix and of each row.
+    pub fn brackets(self, open: char, close: char) -> Self {
+        MatrixFormatter { open, close, ..self }
+    }
+
+    /// The matrix formatted with these options.
+    pub fn format<const R: usize, const C: usize>(&self, m: &ComplexMatrix<R, C>) -> String {
+        let rows: Vec<String> = m.0.iter().map(|row| {
+            let entries: Vec<String> = row.iter().map(|&entry| self.format_entry(entry)).collect();
+            format!("{}{}{}", self.open, entries.join(", "), self.close)
+        }).collect();
+
+        // In a grid, rows line up below the first one, after the outer bracket.
+        let separator = if self.grid { ",\n " } else { ", " };
+        format!("{}{}{}", self.open, rows.join(separator), self.close)
+    }
+
+    fn format_entry(&self, Complex { real, imaginary }: Complex) -> String {
+        format!("{:.*}{:+.*}i", self.precision, real, self.precision, imaginary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> ComplexMatrix<2, 2> {
+        ComplexMatrix::new([[Complex::new(1.0, 0.5), Complex::new(0.0, -1.0)],
+                            [Complex::new(-2.5, 0.0), Complex::new(3.0, 3.0)]])
+    }
+
+    #[test]
+    fn test_grid() {
+        let formatter = MatrixFormatter::default().precision(2).grid(true);
+
+        assert_eq!(formatter.format(&sample()), "[[1.00+0.50i, 0.00-1.00i],\n [-2.50+0.00i, 3.00+3.00i]]");
+    }
+
+    #[test]
+    fn test_custom_brackets() {
+        let formatter = MatrixFormatter { precision: 1, grid: false, open: '{', close: '}' };
+
+        assert_eq!(formatter.format(&sample()), "{{1.0+0.5i, 0.0-1.0i}, {-2.5+0.0i, 3.0+3.0i}}");
+    }
+}
@@ -0,0 +1,47 @@
+//! Serde support for complex matrices, as arrays of rows of complex numbers.
+//! The dimensions are checked when deserializing.
+
+use std::convert::TryFrom;
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+use crate::utils::complex_number::Complex;
+use super::ComplexMatrix;
+
+impl<const R: usize, const C: usize> Serialize for ComplexMatrix<R, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|row| row.as_slice()))
+    }
+}
+
+impl<'de, const R: usize, const C: usize> Deserialize<'de> for ComplexMatrix<R, C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Vec<Complex>>::deserialize(deserializer)?;
+
+        ComplexMatrix::try_from(rows).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let m = ComplexMatrix::new([[Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)],
+                                    [Complex::new(0.5, 0.0), Complex::new(-2.0, 2.25)]]);
+        let json = serde_json::to_string(&m).unwrap();
+
+        let parsed: ComplexMatrix<2, 2> = serde_json::from_str(&json).unwrap();
+        assert!(parsed.approx_eq(&m, 1e-12));
+    }
+
+    #[test]
+    fn test_wrong_dimensions() {
+        let json = serde_json::to_string(&ComplexMatrix::<2, 3>::new([[Complex::ONE; 3]; 2])).unwrap();
+        let error = serde_json::from_str::<ComplexMatrix<2, 2>>(&json).unwrap_err();
+
+        assert!(error.to_string().contains("expected a 2x2 matrix, found 2x3"));
+    }
+}
@@ -1,27 +1,70 @@
-use std::ops::{Add, Mul, Neg, Sub, Div, AddAssign};
-use std::iter::Sum;
-use std::fmt::{Formatter, Result, Display};
-use std::convert::From;
+use core::ops::{Add, Mul, Neg, Sub, Div, AddAssign, MulAssign};
+use core::iter::Sum;
+use core::fmt::{Formatter, Result, Display};
+use core::convert::From;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+use core::f64::consts::{PI, TAU};
+
+use crate::utils::math;
+
+/// Maps any angle into the canonical range (-π, π].
+pub fn wrap_phase(theta: f64) -> f64 {
+    let wrapped = math::rem_euclid(theta, TAU);
+
+    if wrapped > PI { wrapped - TAU } else { wrapped }
+}
 
 /// Polar coordinates representation.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Polar(pub f64, pub f64);
 
 impl From<Complex> for Polar {
-    fn from(Complex { real: r, imaginary: i }: Complex) -> Self {
-        Polar(f64::sqrt(f64::powi(r, 2) + f64::powi(i, 2)), f64::atan(i / r))
+    fn from(z: Complex) -> Self {
+        Polar(z.abs(), z.arg())
     }
 }
 
 impl From<Cartesian> for Polar {
     fn from(Cartesian(x, y): Cartesian) -> Self {
-        Polar(f64::sqrt(f64::powi(x, 2) + f64::powi(y, 2)), f64::atan(y / x))
+        Polar::from(Complex::new(x, y))
+    }
+}
+
+impl Polar {
+    /// Whether both represent the same point up to `epsilon`. Phases are
+    /// compared modulo 2π, and the phase of a zero magnitude is ignored.
+    pub fn approx_eq(self, other: Polar, epsilon: f64) -> bool {
+        if (self.0 - other.0).abs() >= epsilon {
+            return false;
+        }
+
+        self.0.abs() < epsilon || wrap_phase(self.1 - other.1).abs() < epsilon
+    }
+}
+
+// Support for negating complex numbers in polar form, rotating by π.
+impl Neg for Polar {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Polar(self.0, wrap_phase(self.1 + PI))
     }
 }
 
+// Support for displaying polar coordinates. The alternate flag (`{:#}`)
+// shows the phase in degrees instead of radians. A precision, if given,
+// applies to both the magnitude and the phase.
 impl Display for Polar {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "({}, {})", self.0, self.1)
+        let (phase, unit) = if f.alternate() { (self.1.to_degrees(), "°") } else { (self.1, "") };
+
+        match f.precision() {
+            Some(p) => write!(f, "({:.*}, {:.*}{})", p, self.0, p, phase, unit),
+            None => write!(f, "({}, {}{})", self.0, phase, unit),
+        }
     }
 }
 
@@ -37,7 +80,7 @@ impl From<Complex> for Cartesian {
 
 impl From<Polar> for Cartesian {
     fn from(Polar(magnitude, phase): Polar) -> Self {
-        Cartesian(magnitude * f64::cos(phase), magnitude * f64::sin(phase))
+        Cartesian(magnitude * math::cos(phase), magnitude * math::sin(phase))
     }
 }
 
@@ -52,6 +95,7 @@ impl Display for Cartesian {
 /// numeric type for the fields, but always using f64 will
 /// always do for my purposes.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Complex {
     /// The real part of the complex number.
     pub real: f64,
@@ -60,21 +104,173 @@ pub struct Complex {
 }
 
 impl Complex {
+    /// The additive identity, `0+0i`.
+    pub const ZERO: Complex = Complex { real: 0.0, imaginary: 0.0 };
+    /// The multiplicative identity, `1+0i`.
+    pub const ONE: Complex = Complex { real: 1.0, imaginary: 0.0 };
+    /// The imaginary unit, `0+1i`.
+    pub const I: Complex = Complex { real: 0.0, imaginary: 1.0 };
+
     /// Returns a complex number with the given real and
     /// imaginary parts.
     pub fn new(real: f64, imaginary: f64) -> Self {
         Self {real, imaginary}
     }
 
-    pub fn abs(self) -> f64 {
+    /// The squared magnitude, `r² + i²`.
+    pub fn norm_sqr(self) -> f64 {
         let Complex { real: r, imaginary: i } = self;
-        f64::sqrt(f64::powi(r, 2) + f64::powi(i, 2))
+        r * r + i * i
+    }
+
+    /// The real and imaginary parts, as a tuple.
+    pub fn to_tuple(self) -> (f64, f64) {
+        (self.real, self.imaginary)
+    }
+
+    /// The real and imaginary parts, as a tuple, without consuming `self`.
+    pub fn parts(&self) -> (f64, f64) {
+        (self.real, self.imaginary)
+    }
+
+    /// The magnitude `sqrt(r² + i²)`, computed without overflowing or
+    /// underflowing in the squares.
+    pub fn abs(self) -> f64 {
+        math::hypot(self.real, self.imaginary)
     }
 
     pub fn conjugate(self) -> Self {
         let Complex { real: r, imaginary: i } = self;
         Self::new(r, -i)
     }
+
+    /// The point on the unit circle at angle `theta`, `cos θ + i·sin θ`.
+    pub fn from_angle(theta: f64) -> Self {
+        Self::new(math::cos(theta), math::sin(theta))
+    }
+
+    /// Alias of `from_angle`, after the usual notation cis θ = cos θ + i·sin θ.
+    pub fn cis(theta: f64) -> Self {
+        Self::from_angle(theta)
+    }
+
+    /// The `k`-th of the `n` n-th roots of unity, `cis(2πk/n)`. Panics if `n`
+    /// is zero.
+    pub fn root_of_unity(k: usize, n: usize) -> Self {
+        if n == 0 {
+            panic!("There are no 0-th roots of unity!");
+        }
+
+        Self::cis(TAU * (k % n) as f64 / n as f64)
+    }
+
+    /// Rotates the number by `theta` radians around the origin.
+    pub fn rotate(self, theta: f64) -> Self {
+        self * Self::from_angle(theta)
+    }
+
+    /// The unit complex number with the same argument, `self / |self|`.
+    /// Panics on zero, which has no argument, like division by zero.
+    pub fn unit(self) -> Self {
+        self / Self::new(self.abs(), 0.0)
+    }
+
+    /// The argument (phase), in (-π, π].
+    pub fn arg(self) -> f64 {
+        // `atan2` returns -π on the negative real axis when the imaginary part
+        // is -0, so it still needs wrapping.
+        wrap_phase(math::atan2(self.imaginary, self.real))
+    }
+
+    /// The signed angle in (-π, π] of the rotation taking the direction of
+    /// `self` to that of `other`. If either is zero there is no direction, and
+    /// the angle is 0.
+    pub fn arg_between(self, other: Complex) -> f64 {
+        if self == Self::ZERO || other == Self::ZERO {
+            return 0.0;
+        }
+
+        wrap_phase((other / self).arg())
+    }
+
+    /// The principal square root, with nonnegative real part.
+    pub fn sqrt(self) -> Self {
+        let Complex { real: r, imaginary: i } = self;
+        let magnitude = self.abs();
+        Self::new(math::sqrt((magnitude + r) / 2.0), math::copysign(math::sqrt((magnitude - r) / 2.0), i))
+    }
+
+    /// The complex exponential `e^self`.
+    pub fn exp(self) -> Self {
+        let magnitude = math::exp(self.real);
+        Self::new(magnitude * math::cos(self.imaginary), magnitude * math::sin(self.imaginary))
+    }
+
+    /// The principal branch of the natural logarithm, with the imaginary part
+    /// in (-π, π]. Undefined at zero.
+    pub fn ln(self) -> Self {
+        Self::new(math::ln(self.abs()), self.arg())
+    }
+
+    /// Raises to a complex power, as `e^(exp·ln(self))`. Uses the principal
+    /// branch of the logarithm, and is undefined when `self` is zero.
+    pub fn powc(self, exp: Complex) -> Self {
+        (exp * self.ln()).exp()
+    }
+
+    /// Rescales to magnitude `max` if larger, keeping the argument. Numbers
+    /// with magnitude up to `max`, including zero, are returned unchanged.
+    pub fn clamp_magnitude(self, max: f64) -> Self {
+        let magnitude = self.abs();
+
+        if magnitude > max {
+            self * Self::new(max / magnitude, 0.0)
+        } else {
+            self
+        }
+    }
+
+    /// Rounds both parts to the given number of decimal places. Meant for
+    /// cleaning up output, not for use in computations.
+    pub fn round_to(self, decimals: u32) -> Self {
+        let factor = math::powi(10.0, decimals as i32);
+        let round = |x: f64| math::round(x * factor) / factor;
+        Self::new(round(self.real), round(self.imaginary))
+    }
+
+    /// Sets to zero any part with magnitude below `epsilon`. Meant for
+    /// cleaning up output, not for use in computations.
+    pub fn snap_to_zero(self, epsilon: f64) -> Self {
+        let snap = |x: f64| if x.abs() < epsilon { 0.0 } else { x };
+        Self::new(snap(self.real), snap(self.imaginary))
+    }
+
+    /// Linear interpolation `self·(1-t) + other·t`. The parameter `t` is not
+    /// clamped, so values outside [0, 1] extrapolate along the same line.
+    pub fn lerp(self, other: Complex, t: f64) -> Self {
+        self * Self::new(1.0 - t, 0.0) + other * Self::new(t, 0.0)
+    }
+
+    /// Multiplies by `i`, i.e. rotates by 90°, without a full product.
+    pub fn mul_i(self) -> Self {
+        Self::new(-self.imaginary, self.real)
+    }
+
+    /// Multiplies by `-i`, i.e. rotates by -90°, without a full product.
+    pub fn mul_neg_i(self) -> Self {
+        Self::new(self.imaginary, -self.real)
+    }
+
+    /// Divides by `other`, or returns `None` if its magnitude is below
+    /// `epsilon`. Unlike `/`, which only rejects an exact zero, this catches
+    /// ill-conditioned divisions before they blow up.
+    pub fn div_with_tolerance(self, other: Complex, epsilon: f64) -> Option<Self> {
+        if other.norm_sqr() < epsilon * epsilon {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
 }
 
 impl From<Polar> for Complex {
@@ -128,6 +324,13 @@ impl Mul for Complex {
     }
 }
 
+// Support for assigning multiplication result of complex numbers.
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
 // Support for negating complex numbers.
 impl Neg for Complex {
     type Output = Self;
@@ -158,8 +361,8 @@ impl Div for Complex {
         let Complex { real: r1, imaginary: i1 } = self;
         let Complex { real: r2, imaginary: i2 } = other;
 
-        let real_part = ((r1 * r2) + (i1 * i2)) / (f64::powi(r2, 2) + f64::powi(i2, 2));
-        let imaginary_part = ((r2 * i1) - (r1 * i2)) / (f64::powi(r2, 2) + f64::powi(i2, 2));
+        let real_part = ((r1 * r2) + (i1 * i2)) / (r2 * r2 + i2 * i2);
+        let imaginary_part = ((r2 * i1) - (r1 * i2)) / (r2 * r2 + i2 * i2);
 
         Self::new(real_part, imaginary_part)
     }
@@ -168,11 +371,73 @@ impl Div for Complex {
 // Support for displaying complex numbers.
 impl Display for Complex {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        let operator = if self.imaginary >= 0.0 { "+" } else { "" };
+        // The sign of -0 is printed by the number itself, so it needs no `+`.
+        let operator = if self.imaginary.is_sign_negative() { "" } else { "+" };
         write!(f, "{}{}{}i", self.real, operator, self.imaginary)
     }
 }
 
+/// Error returned when a string can't be parsed as a complex number.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseComplexError(String);
+
+#[cfg(feature = "std")]
+impl Display for ParseComplexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "invalid complex number: \"{}\"", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseComplexError {}
+
+#[cfg(feature = "std")]
+// Support for parsing complex numbers in the same `a+bi` form they are
+// displayed in. Either part may be omitted, as in `3`, `-2i` or `i`.
+impl FromStr for Complex {
+    type Err = ParseComplexError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let trimmed: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let error = || ParseComplexError(s.to_string());
+        let bytes = trimmed.as_bytes();
+
+        // The sign separating both parts, skipping a leading sign, the sign of
+        // an exponent, and a sign right after another one, as in `1+-0i`.
+        let split = (1..bytes.len()).rev().find(|&k| {
+            (bytes[k] == b'+' || bytes[k] == b'-') && !matches!(bytes[k - 1], b'e' | b'E' | b'+' | b'-')
+        });
+
+        match (trimmed.strip_suffix('i'), split) {
+            (None, _) => {
+                let real = trimmed.parse().map_err(|_| error())?;
+                Ok(Self::new(real, 0.0))
+            },
+            (Some(imaginary), None) => {
+                let imaginary = parse_imaginary(imaginary).ok_or_else(error)?;
+                Ok(Self::new(0.0, imaginary))
+            },
+            (Some(imaginary), Some(k)) => {
+                let real = trimmed[..k].parse().map_err(|_| error())?;
+                let imaginary = parse_imaginary(&imaginary[k..]).ok_or_else(error)?;
+                Ok(Self::new(real, imaginary))
+            },
+        }
+    }
+}
+
+/// Parses the coefficient of an imaginary part, where a lone sign stands for 1.
+#[cfg(feature = "std")]
+fn parse_imaginary(s: &str) -> Option<f64> {
+    match s {
+        "" | "+" => Some(1.0),
+        "-" => Some(-1.0),
+        // A `+` separator followed by the sign of the coefficient itself.
+        _ => s.strip_prefix('+').unwrap_or(s).parse().ok(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,6 +460,13 @@ mod tests {
         assert_eq!(Complex::new(-3.0, 1.0) * Complex::new(2.0, -4.0), Complex::new(-2.0, 14.0));
     }
 
+    #[test]
+    fn test_mul_assign() {
+        let mut number = Complex::new(-3.0, 1.0);
+        number *= Complex::new(2.0, -4.0);
+        assert_eq!(number, Complex::new(-2.0, 14.0));
+    }
+
     #[test]
     fn test_mul_identity() {
         let number1 = Complex::new(9.6, -3.4);
@@ -209,6 +481,16 @@ mod tests {
         assert_eq!(Complex::new(0.0, 3.0) / Complex::new(-1.0, -1.0), Complex::new(-1.5, -1.5));
     }
 
+    #[test]
+    fn test_div_with_tolerance() {
+        let z = Complex::new(-2.0, 1.0);
+
+        assert_eq!(z.div_with_tolerance(Complex::new(1.0, 2.0), 1e-12), Some(Complex::I));
+        assert_eq!(z.div_with_tolerance(Complex::new(1e-20, 0.0), 1e-12), None);
+        assert_eq!(z.div_with_tolerance(Complex::new(0.0, -1e-20), 1e-12), None);
+        assert_eq!(z.div_with_tolerance(Complex::ZERO, 1e-12), None);
+    }
+
     #[test]
     fn test_neg() {
         assert_eq!(-Complex::new(-3.0, 1.0), Complex::new(3.0, -1.0));
@@ -220,6 +502,11 @@ mod tests {
         assert_eq!(Complex::abs(Complex::new(4.0, -3.0)), 5.0);
     }
 
+    #[test]
+    fn test_norm_sqr() {
+        assert_eq!(Complex::norm_sqr(Complex::new(4.0, -3.0)), 25.0);
+    }
+
     #[test]
     fn test_conjugate() {
         assert_eq!(Complex::conjugate(Complex::new(4.0, -3.0)), Complex::new(4.0, 3.0));
@@ -232,6 +519,22 @@ mod tests {
         assert_eq!(Polar::from(Cartesian(1.0, 1.0)), Polar(f64::sqrt(2.0), f64::atan(1.0)));
     }
 
+    #[test]
+    fn test_polar_quadrants() {
+        let second = Polar::from(Complex::new(-1.0, 1.0));
+        assert!(second.approx_eq(Polar(f64::sqrt(2.0), 3.0 * PI / 4.0), 1e-12));
+
+        let third = Complex::new(-1.0, -1.0);
+        assert!(Polar::from(third).approx_eq(Polar(f64::sqrt(2.0), -3.0 * PI / 4.0), 1e-12));
+        assert!((Complex::from(Polar::from(third)) - third).abs() < 1e-12);
+        assert!(Polar::from(Cartesian(-1.0, -1.0)).approx_eq(Polar::from(third), 1e-12));
+
+        // The negative real axis maps to π, from either side of the cut.
+        assert_eq!(Polar::from(Complex::new(-1.0, 0.0)), Polar(1.0, PI));
+        assert_eq!(Polar::from(Complex::new(-1.0, -0.0)), Polar(1.0, PI));
+        assert_eq!(Polar::from(Complex::ZERO), Polar(0.0, 0.0));
+    }
+
     #[test]
     fn test_polar_to_cartesian() {
         let Cartesian(x, y) = Cartesian::from(Polar(f64::sqrt(2.0), f64::atan(1.0)));
@@ -239,4 +542,221 @@ mod tests {
         // Allow for some rounding errors.
         assert!(f64::abs(x - 1.0) < 0.01 && f64::abs(y - 1.0) < 0.01);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str() {
+        assert_eq!("3+2i".parse(), Ok(Complex::new(3.0, 2.0)));
+        assert_eq!(" -1.5 - 0.25i ".parse(), Ok(Complex::new(-1.5, -0.25)));
+        assert_eq!("4".parse(), Ok(Complex::new(4.0, 0.0)));
+        assert_eq!("-i".parse(), Ok(Complex::new(0.0, -1.0)));
+        assert_eq!("1e-3+2e2i".parse(), Ok(Complex::new(0.001, 200.0)));
+        assert!("1+2j".parse::<Complex>().is_err());
+        assert!("".parse::<Complex>().is_err());
+
+        let c = Complex::new(-9.6, 3.4);
+        assert_eq!(c.to_string().parse(), Ok(c));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_negative_zero_round_trip() {
+        let c = Complex::new(1.0, -0.0);
+        assert_eq!(c.to_string(), "1-0i");
+
+        for text in [c.to_string().as_str(), "1+-0i", "-0+-0i"] {
+            let parsed: Complex = text.parse().unwrap();
+            assert!(parsed.imaginary == 0.0 && parsed.imaginary.is_sign_negative());
+        }
+
+        assert_eq!("2+-3i".parse(), Ok(Complex::new(2.0, -3.0)));
+        assert!("1+-+0i".parse::<Complex>().is_err());
+    }
+
+    #[test]
+    fn test_mul_i() {
+        let z = Complex::new(2.5, -4.0);
+        assert_eq!(z.mul_i(), z * Complex::I);
+        assert_eq!(z.mul_neg_i(), z * -Complex::I);
+        assert_eq!(Complex::ONE.mul_i().mul_i(), -Complex::ONE);
+    }
+
+    #[test]
+    fn test_wrap_phase() {
+        assert!(f64::abs(wrap_phase(3.0 * PI) - PI) < 1e-12);
+        assert!(f64::abs(wrap_phase(-3.0 * PI) - PI) < 1e-12);
+        assert!(f64::abs(wrap_phase(2.5 * PI) - PI / 2.0) < 1e-12);
+        assert_eq!(wrap_phase(PI), PI);
+        assert_eq!(wrap_phase(-PI), PI);
+        assert_eq!(wrap_phase(1.0), 1.0);
+        assert_eq!(wrap_phase(-1.0), -1.0);
+
+        for &theta in &[3.0 * PI, -3.0 * PI, 0.5, -2.0, 100.0] {
+            assert_eq!(wrap_phase(wrap_phase(theta)), wrap_phase(theta));
+        }
+    }
+
+    #[test]
+    fn test_polar_neg() {
+        let Polar(magnitude, phase) = -Polar(2.0, PI / 2.0);
+        assert_eq!(magnitude, 2.0);
+        assert!(f64::abs(phase + PI / 2.0) < 1e-12);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Complex::new(1.0, -2.0);
+        let b = Complex::new(3.0, 4.0);
+
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), (a + b) / Complex::new(2.0, 0.0));
+        assert_eq!(a.lerp(b, 2.0), Complex::new(5.0, 10.0));
+    }
+
+    #[test]
+    fn test_polar_display() {
+        assert_eq!(format!("{:#}", Polar(1.0, PI)), "(1, 180°)");
+        assert_eq!(format!("{}", Polar(1.0, PI)), format!("(1, {})", PI));
+        assert_eq!(format!("{:.2}", Polar(2.0, PI)), "(2.00, 3.14)");
+        assert_eq!(format!("{:#.1}", Polar(2.0, PI / 4.0)), "(2.0, 45.0°)");
+    }
+
+    #[test]
+    fn test_round_to() {
+        let amplitude = std::f64::consts::FRAC_1_SQRT_2;
+        assert_eq!(Complex::new(amplitude, -amplitude).round_to(3), Complex::new(0.707, -0.707));
+        assert_eq!(Complex::new(2.5, 1.49).round_to(0), Complex::new(3.0, 1.0));
+    }
+
+    #[test]
+    fn test_snap_to_zero() {
+        assert_eq!(Complex::new(0.5, 1e-12).snap_to_zero(1e-10), Complex::new(0.5, 0.0));
+        assert_eq!(Complex::new(-1e-12, 1e-3).snap_to_zero(1e-10), Complex::new(0.0, 1e-3));
+    }
+
+    #[test]
+    fn test_clamp_magnitude() {
+        let large = Complex::new(30.0, -40.0);
+        let clamped = large.clamp_magnitude(5.0);
+
+        assert!(f64::abs(clamped.abs() - 5.0) < 1e-12);
+        assert!(f64::abs(f64::atan2(clamped.imaginary, clamped.real) - f64::atan2(-40.0, 30.0)) < 1e-12);
+        assert_eq!(Complex::new(1.0, 1.0).clamp_magnitude(5.0), Complex::new(1.0, 1.0));
+        assert_eq!(Complex::ZERO.clamp_magnitude(0.0), Complex::ZERO);
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        assert_eq!(Complex::ZERO.exp(), Complex::ONE);
+        assert!((Complex::new(0.0, PI).exp() - Complex::new(-1.0, 0.0)).abs() < 1e-12);
+        assert!((Complex::new(-1.0, 0.0).ln() - Complex::new(0.0, PI)).abs() < 1e-12);
+
+        let z = Complex::new(1.5, -2.0);
+        assert!((z.ln().exp() - z).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_arg_negative_zero() {
+        let z = Complex::new(-1.0, -0.0);
+
+        assert_eq!(z.arg(), PI);
+        assert_eq!(z.ln(), Complex::new(0.0, PI));
+        assert!((z.powc(Complex::new(0.5, 0.0)) - Complex::I).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_powc() {
+        let e = Complex::new(std::f64::consts::E, 0.0);
+        assert!((e.powc(Complex::new(0.0, PI)) - Complex::new(-1.0, 0.0)).abs() < 1e-12);
+
+        let z = Complex::new(1.0, 1.0);
+        assert!((z.powc(Complex::new(2.0, 0.0)) - z * z).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_unit() {
+        let unit = Complex::new(3.0, 4.0).unit();
+
+        assert!((unit - Complex::new(0.6, 0.8)).abs() < 1e-12);
+        assert!(f64::abs(unit.abs() - 1.0) < 1e-12);
+        assert!(f64::abs(Complex::new(-2.0, 7.0).unit().abs() - 1.0) < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by zero!")]
+    fn test_unit_zero() {
+        Complex::ZERO.unit();
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_eq!(Complex::new(9.0, 0.0).sqrt(), Complex::new(3.0, 0.0));
+
+        let z = Complex::new(3.0, -4.0);
+        assert!((z.sqrt() * z.sqrt() - z).abs() < 1e-12);
+        assert!(z.sqrt().real >= 0.0);
+    }
+
+    #[test]
+    fn test_polar_approx_eq() {
+        assert_ne!(Polar(1.0, 0.0), Polar(1.0, TAU));
+        assert!(Polar(1.0, 0.0).approx_eq(Polar(1.0, TAU), 1e-12));
+        assert!(Polar(2.0, PI).approx_eq(Polar(2.0, -PI), 1e-12));
+        assert!(Polar(0.0, 1.0).approx_eq(Polar(0.0, -2.5), 1e-12));
+
+        assert!(!Polar(1.0, 0.0).approx_eq(Polar(1.0, PI), 1e-12));
+        assert!(!Polar(1.0, 0.5).approx_eq(Polar(2.0, 0.5), 1e-12));
+    }
+
+    #[test]
+    fn test_from_angle_rotate() {
+        assert!((Complex::from_angle(PI / 2.0) - Complex::I).abs() < 1e-12);
+        assert!((Complex::from_angle(1.3).abs() - 1.0).abs() < 1e-12);
+
+        let z = Complex::new(3.0, -2.0);
+        assert!((z.rotate(PI) + z).abs() < 1e-12);
+        assert!((z.rotate(PI / 2.0) - z.mul_i()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_arg_between() {
+        assert!((Complex::ONE.arg_between(Complex::I) - PI / 2.0).abs() < 1e-12);
+        assert!((Complex::ONE.arg_between(Complex::new(-1.0, 0.0)) - PI).abs() < 1e-12);
+        assert!((Complex::I.arg_between(Complex::ONE) + PI / 2.0).abs() < 1e-12);
+        assert_eq!(Complex::ZERO.arg_between(Complex::I), 0.0);
+    }
+
+    #[test]
+    fn test_abs_large_components() {
+        let big = 2.0 * f64::MAX.sqrt();
+        let z = Complex::new(big, big);
+
+        assert!(f64::sqrt(big * big + big * big).is_infinite());
+        assert!(z.abs().is_finite());
+        assert!((z.abs() / big - std::f64::consts::SQRT_2).abs() < 1e-12);
+        assert_eq!(Complex::new(3.0, -4.0).abs(), 5.0);
+    }
+
+    #[test]
+    fn test_to_tuple() {
+        let z = Complex::new(1.0, 2.0);
+
+        assert_eq!(z.to_tuple(), (1.0, 2.0));
+        assert_eq!(z.parts(), z.to_tuple());
+    }
+
+    #[test]
+    fn test_roots_of_unity() {
+        assert_eq!(Complex::root_of_unity(0, 7), Complex::ONE);
+        assert!((Complex::root_of_unity(1, 4) - Complex::I).abs() < 1e-12);
+        assert!((Complex::cis(PI) + Complex::ONE).abs() < 1e-12);
+
+        for n in 1..10 {
+            let roots: Complex = (0..n).map(|k| Complex::root_of_unity(k, n)).sum();
+            let expected = if n == 1 { Complex::ONE } else { Complex::ZERO };
+            assert!((roots - expected).abs() < 1e-12);
+        }
+    }
 }
@@ -1,6 +1,20 @@
-use std::{fmt::Display, ops::{Add, Sub, Mul, Neg}};
+use core::{iter::Sum, ops::{Add, Sub, Mul, Neg, AddAssign, MulAssign}};
+#[cfg(feature = "std")]
+use std::{fmt::Display, error::Error, str::FromStr};
 
 use crate::utils::complex_number::Complex;
+#[cfg(feature = "std")]
+use crate::utils::complex_number::ParseComplexError;
+use crate::utils::complex_matrix::ComplexMatrix;
+#[cfg(feature = "std")]
+use crate::utils::bits::index_to_binary_string;
+use crate::utils::math;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
+/// Norms below this are considered zero when normalizing.
+const NORM_THRESHOLD: f64 = 1e-12;
 
 /// Newtype pattern for complex vectors.
 /// I should have probably gone with generics, but I think complex will do just
@@ -10,13 +24,330 @@ use crate::utils::complex_number::Complex;
 pub struct ComplexVector<const N: usize>(pub [Complex; N]);
 
 impl<const N: usize> ComplexVector<N> {
-    pub fn distance_to(self, rhs: ComplexVector<N>) -> f64 {
-        let diff = self - rhs;
-        diff.norm()
+    /// Vector with every entry equal to zero.
+    pub fn zeros() -> Self {
+        ComplexVector([Complex::ZERO; N])
+    }
+
+    /// Vector with every entry equal to one.
+    pub fn ones() -> Self {
+        ComplexVector([Complex::ONE; N])
+    }
+
+    /// Normalized state with the given amplitudes at the given indices, and
+    /// zeros elsewhere. Amplitudes for repeated indices are added up. Returns
+    /// `None` if the result is the zero vector.
+    pub fn superposition(terms: &[(usize, Complex)]) -> Option<Self> {
+        let mut state = Self::zeros();
+
+        for &(index, amplitude) in terms {
+            state.0[index] += amplitude;
+        }
+
+        state.try_normalize().map(|(unit, _)| unit)
+    }
+
+    /// The linear combination Σ cᵢ·vᵢ of the given (coefficient, vector)
+    /// pairs. Unlike `superposition`, the result is not normalized.
+    pub fn weighted_sum(terms: &[(Complex, ComplexVector<N>)]) -> Self {
+        terms.iter().fold(Self::zeros(), |acc, &(c, v)| acc + v * c)
+    }
+
+    /// Embeds the vector into a larger space, filling the new trailing entries
+    /// with zeros. M must be at least N, which is checked on debug builds.
+    pub fn pad<const M: usize>(self) -> ComplexVector<M> {
+        debug_assert!(M >= N, "Cannot pad a vector of size {} to the smaller size {}.", N, M);
+        resize_vector(self)
+    }
+
+    /// Keeps only the first M entries of the vector. M must be at most N,
+    /// which is checked on debug builds.
+    pub fn truncate<const M: usize>(self) -> ComplexVector<M> {
+        debug_assert!(M <= N, "Cannot truncate a vector of size {} to the larger size {}.", N, M);
+        resize_vector(self)
+    }
+
+    /// Views the vector as a ROWS×COLS matrix, filled row by row. ROWS*COLS
+    /// must be N, which is checked on debug builds. Inverse of
+    /// `ComplexMatrix::flatten`.
+    pub fn reshape<const ROWS: usize, const COLS: usize>(self) -> ComplexMatrix<ROWS, COLS> {
+        debug_assert!(ROWS * COLS == N, "Cannot reshape a vector of size {} into a {}x{} matrix.", N, ROWS, COLS);
+        let mut m = ComplexMatrix::new([[Complex::ZERO; COLS]; ROWS]);
+
+        for (i, &x) in self.0.iter().enumerate().take(ROWS * COLS) {
+            m[[i / COLS, i % COLS]] = x;
+        }
+
+        m
+    }
+
+    /// Stacks `other` after the entries of the vector. This is a plain
+    /// concatenation, not the Kronecker product that composes the states of
+    /// two subsystems. Panics unless K is N + M.
+    pub fn concat<const M: usize, const K: usize>(self, other: ComplexVector<M>) -> ComplexVector<K> {
+        assert!(K == N + M, "Cannot concatenate vectors of sizes {} and {} into size {}.", N, M, K);
+        let mut result = ComplexVector::zeros();
+
+        for (x, &y) in result.0.iter_mut().zip(self.0.iter().chain(other.0.iter())) {
+            *x = y;
+        }
+
+        result
+    }
+
+    /// Splits the vector into its first A entries and the remaining B. Panics
+    /// unless A + B is N. Inverse of `concat`.
+    pub fn split<const A: usize, const B: usize>(self) -> (ComplexVector<A>, ComplexVector<B>) {
+        assert!(A + B == N, "Cannot split a vector of size {} into sizes {} and {}.", N, A, B);
+        let mut tail = ComplexVector::zeros();
+
+        for (x, &y) in tail.0.iter_mut().zip(self.0.iter().skip(A)) {
+            *x = y;
+        }
+
+        (self.truncate(), tail)
+    }
+
+    /// Inner product ⟨self|other⟩, borrowing both vectors instead of moving
+    /// them as the `*` operator does.
+    pub fn inner_product(&self, other: &Self) -> Complex {
+        inner_product_vector(self, other)
+    }
+
+    /// Projection onto the line spanned by `basis`, i.e.
+    /// `(⟨basis|self⟩ / ⟨basis|basis⟩) · basis`. The zero vector spans no
+    /// line, so projecting onto it gives the zero vector.
+    pub fn project_onto(&self, basis: &ComplexVector<N>) -> ComplexVector<N> {
+        if basis.is_zero(NORM_THRESHOLD) {
+            return ComplexVector::zeros();
+        }
+
+        *basis * (basis.inner_product(self) / basis.inner_product(basis))
+    }
+
+    /// Inner product of two real vectors, as a real number. Whether the
+    /// product is indeed real is checked on debug builds.
+    pub fn dot_real(&self, other: &Self) -> f64 {
+        let product = self.inner_product(other);
+        debug_assert!(product.imaginary.abs() < 1e-9, "The inner product {} is not real.", product);
+
+        product.real
+    }
+
+    /// Entrywise (Schur) product, multiplying corresponding entries. Not to be
+    /// confused with the Hadamard gate.
+    pub fn hadamard_product(self, ComplexVector(other): Self) -> ComplexVector<N> {
+        let mut v = self;
+
+        for (x, y) in v.0.iter_mut().zip(other) {
+            *x *= y;
+        }
+
+        v
+    }
+
+    /// Cyclically shifts the amplitudes to the right: the amplitude at index
+    /// `i` moves to `(i + shift) % N`, and the last ones wrap to the front.
+    pub fn rotate_indices(self, shift: usize) -> ComplexVector<N> {
+        let mut v = self;
+
+        if N > 0 {
+            v.0.rotate_right(shift % N);
+        }
+
+        v
+    }
+
+    /// Inverse over addition, by negating each coordinate.
+    pub fn negate(self) -> ComplexVector<N> {
+        inverse_vector(self)
+    }
+
+    /// Euclidean distance ‖self - other‖, borrowing both vectors.
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        (self - other).norm()
     }
 
     pub fn norm(self) -> f64 {
-        (self * self).real.sqrt()
+        math::sqrt((self * self).real)
+    }
+
+    /// Whether the norm is below `epsilon`.
+    pub fn is_zero(&self, epsilon: f64) -> bool {
+        self.norm() < epsilon
+    }
+
+    /// Unit vector with the same direction. Panics on a (near) zero vector.
+    pub fn normalize(self) -> ComplexVector<N> {
+        match self.try_normalize() {
+            Some((unit, _)) => unit,
+            None => panic!("Cannot normalize the zero vector!"),
+        }
+    }
+
+    /// Unit vector with the same direction, together with the norm it was
+    /// divided by. Returns `None` on a (near) zero vector.
+    pub fn try_normalize(self) -> Option<(ComplexVector<N>, f64)> {
+        if self.is_zero(NORM_THRESHOLD) {
+            return None;
+        }
+
+        let norm = self.norm();
+        Some((self * Complex::new(1.0 / norm, 0.0), norm))
+    }
+
+    /// The vector as a sum of kets over the computational basis, as in
+    /// `0.707|00⟩ + 0.707|11⟩`. Each basis state is labeled by its index in
+    /// binary, padded to `n_qubits` bits. Terms whose amplitude has magnitude
+    /// below `epsilon` are dropped, as are real or imaginary parts below it.
+    /// Amplitudes are shown with three decimals.
+    #[cfg(feature = "std")]
+    pub fn to_ket_string(&self, n_qubits: usize, epsilon: f64) -> String {
+        let mut result = String::new();
+
+        for (i, &amplitude) in self.0.iter().enumerate() {
+            if amplitude.abs() < epsilon {
+                continue;
+            }
+
+            let Complex { real: r, imaginary: im } = amplitude;
+            let (negative, coefficient) = if im.abs() < epsilon {
+                (r < 0.0, format!("{:.3}", r.abs()))
+            } else if r.abs() < epsilon {
+                (im < 0.0, format!("{:.3}i", im.abs()))
+            } else {
+                (false, format!("({:.3}{:+.3}i)", r, im))
+            };
+
+            let sign = match (result.is_empty(), negative) {
+                (true, true) => "-",
+                (true, false) => "",
+                (false, true) => " - ",
+                (false, false) => " + ",
+            };
+
+            result.push_str(&format!("{}{}|{}⟩", sign, coefficient, index_to_binary_string(i, n_qubits)));
+        }
+
+        if result.is_empty() {
+            result.push('0');
+        }
+
+        result
+    }
+
+    /// Indices of the entries, sorted by descending magnitude. Entries with
+    /// the same magnitude keep their original order.
+    #[cfg(feature = "std")]
+    pub fn indices_by_magnitude(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..N).collect();
+        indices.sort_by(|&i, &j| self.0[j].abs().total_cmp(&self.0[i].abs()));
+        indices
+    }
+
+    /// The largest magnitude among the entries, or 0 for an empty vector.
+    pub fn max_abs(&self) -> f64 {
+        self.0.iter().map(|x| x.abs()).fold(0.0, f64::max)
+    }
+
+    /// The smallest magnitude among the entries, or 0 for an empty vector.
+    pub fn min_abs(&self) -> f64 {
+        self.0.iter().map(|x| x.abs()).reduce(f64::min).unwrap_or(0.0)
+    }
+
+    /// Index of the most probable measurement outcome, i.e. of the largest
+    /// `|amplitude|²`. On ties the first index wins.
+    pub fn argmax_probability(&self) -> usize {
+        let probabilities = self.0.map(Complex::norm_sqr);
+
+        (0..N).fold(0, |best, i| if probabilities[i] > probabilities[best] { i } else { best })
+    }
+
+    /// Whether the inner product with `other` is zero, up to `epsilon`.
+    pub fn is_orthogonal(&self, other: &Self, epsilon: f64) -> bool {
+        (*self * *other).abs() < epsilon
+    }
+}
+
+/// Expectation values of the Pauli observables on a single-qubit state
+/// α|0> + β|1>, in closed form. Like `measurement::expectation`, they assume
+/// the state is normalized. Together they are the Bloch vector of the state.
+impl ComplexVector<2> {
+    /// ⟨X⟩ = 2·Re(α*β).
+    pub fn expectation_x(&self) -> f64 {
+        2.0 * (self.0[0].conjugate() * self.0[1]).real
+    }
+
+    /// ⟨Y⟩ = 2·Im(α*β).
+    pub fn expectation_y(&self) -> f64 {
+        2.0 * (self.0[0].conjugate() * self.0[1]).imaginary
+    }
+
+    /// ⟨Z⟩ = |α|² - |β|².
+    pub fn expectation_z(&self) -> f64 {
+        self.0[0].norm_sqr() - self.0[1].norm_sqr()
+    }
+}
+
+/// Whether the vectors form an orthonormal basis of the space: there must be
+/// exactly N of them, each with unit norm and pairwise orthogonal, up to
+/// `epsilon`.
+pub fn is_orthonormal_basis<const N: usize>(vectors: &[ComplexVector<N>], epsilon: f64) -> bool {
+    if vectors.len() != N {
+        return false;
+    }
+
+    let unit_norms = vectors.iter().all(|v| (v.norm() - 1.0).abs() < epsilon);
+    let orthogonal = vectors.iter().enumerate().all(|(i, v)| {
+        vectors[i + 1..].iter().all(|w| v.is_orthogonal(w, epsilon))
+    });
+
+    unit_norms && orthogonal
+}
+
+/// Orthonormalizes the vectors with the (modified) Gram–Schmidt process: each
+/// one has the projections onto the previous ones removed, and is then
+/// normalized. Returns `None` if they are linearly dependent.
+#[cfg(feature = "std")]
+pub fn gram_schmidt<const N: usize>(vectors: &[ComplexVector<N>]) -> Option<Vec<ComplexVector<N>>> {
+    let mut basis: Vec<ComplexVector<N>> = Vec::with_capacity(vectors.len());
+
+    for &v in vectors {
+        let mut w = v;
+
+        for u in &basis {
+            w = w - w.project_onto(u);
+        }
+
+        basis.push(w.try_normalize()?.0);
+    }
+
+    Some(basis)
+}
+
+/// The Gram matrix of K vectors, whose entry (i, j) is ⟨v_i|v_j⟩. It is
+/// singular exactly when the vectors are linearly dependent. Panics if the
+/// number of vectors isn't K.
+pub fn gram_matrix<const N: usize, const K: usize>(vectors: &[ComplexVector<N>]) -> ComplexMatrix<K, K> {
+    if vectors.len() != K {
+        panic!("The Gram matrix of {} vectors is {}x{}, not {}x{}.", vectors.len(), vectors.len(), vectors.len(), K, K);
+    }
+
+    let mut m = ComplexMatrix::new([[Complex::ZERO; K]; K]);
+
+    for (i, v) in vectors.iter().enumerate() {
+        for (j, w) in vectors.iter().enumerate() {
+            m[[i, j]] = v.inner_product(w);
+        }
+    }
+
+    m
+}
+
+/// Support for building complex vectors from arrays, as in `array.into()`.
+impl<const N: usize> From<[Complex; N]> for ComplexVector<N> {
+    fn from(entries: [Complex; N]) -> Self {
+        ComplexVector(entries)
     }
 }
 
@@ -29,6 +360,24 @@ impl<const N: usize> Add for ComplexVector<N> {
     }
 }
 
+/// Support for in-place addition of complex vectors.
+impl<const N: usize> AddAssign for ComplexVector<N> {
+    fn add_assign(&mut self, ComplexVector(rhs): Self) {
+        for (x, y) in self.0.iter_mut().zip(rhs) {
+            *x += y;
+        }
+    }
+}
+
+/// Support for adding many complex vectors.
+impl<const N: usize> Sum for ComplexVector<N> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item=Self> {
+        iter.fold(ComplexVector::zeros(), |acc, x| acc + x)
+    }
+}
+
 // Support for subtracting complex vectors.
 impl<const N: usize> Sub for ComplexVector<N> {
     type Output = Self;
@@ -38,6 +387,42 @@ impl<const N: usize> Sub for ComplexVector<N> {
     }
 }
 
+/// Support for subtracting borrowed complex vectors, leaving both operands
+/// usable.
+impl<const N: usize> Sub for &ComplexVector<N> {
+    type Output = ComplexVector<N>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let mut result = *self;
+
+        for (x, &y) in result.0.iter_mut().zip(other.0.iter()) {
+            *x = *x - y;
+        }
+
+        result
+    }
+}
+
+/// Support for adding a scalar to every entry of a complex vector. This
+/// broadcasts the scalar, and is unrelated to vector addition.
+impl<const N: usize> Add<Complex> for ComplexVector<N> {
+    type Output = Self;
+
+    fn add(self, scalar: Complex) -> Self::Output {
+        ComplexVector(self.0.map(|x| x + scalar))
+    }
+}
+
+/// Support for subtracting a scalar from every entry of a complex vector.
+/// This broadcasts the scalar, and is unrelated to vector subtraction.
+impl<const N: usize> Sub<Complex> for ComplexVector<N> {
+    type Output = Self;
+
+    fn sub(self, scalar: Complex) -> Self::Output {
+        ComplexVector(self.0.map(|x| x - scalar))
+    }
+}
+
 /// Support for scalar product on complex vectors.
 impl<const N: usize> Mul<Complex> for ComplexVector<N> {
     type Output = Self;
@@ -47,11 +432,29 @@ impl<const N: usize> Mul<Complex> for ComplexVector<N> {
     }
 }
 
+/// Support for in-place scalar product on complex vectors.
+impl<const N: usize> MulAssign<Complex> for ComplexVector<N> {
+    fn mul_assign(&mut self, rhs: Complex) {
+        for x in self.0.iter_mut() {
+            *x *= rhs;
+        }
+    }
+}
+
+/// Support for scalar product on complex vectors, with the scalar on the left.
+impl<const N: usize> Mul<ComplexVector<N>> for Complex {
+    type Output = ComplexVector<N>;
+
+    fn mul(self, rhs: ComplexVector<N>) -> Self::Output {
+        product_vector_scalar(rhs, self)
+    }
+}
+
 impl<const N: usize> Mul<ComplexVector<N>> for ComplexVector<N> {
     type Output = Complex;
 
     fn mul(self, rhs: ComplexVector<N>) -> Self::Output {
-        inner_product_vector(self, rhs)
+        self.inner_product(&rhs)
     }
 }
 
@@ -60,13 +463,18 @@ impl<const N: usize> Neg for ComplexVector<N> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        inverse_vector(self)
+        self.negate()
     }
 }
 
 /// Support for displaying complex vectors.
+#[cfg(feature = "std")]
 impl<const N: usize> Display for ComplexVector<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if N == 0 {
+            return write!(f, "[]");
+        }
+
         let result_string = self.0.iter()
                                   .map(|c| c.to_string())
                                   .fold(String::new(), |acc, c| acc + &c + ", ");
@@ -76,15 +484,83 @@ impl<const N: usize> Display for ComplexVector<N> {
     }
 }
 
+/// Copies the entries shared by both sizes, leaving any remaining ones as zero.
+fn resize_vector<const N: usize, const M: usize>(ComplexVector(vector): ComplexVector<N>) -> ComplexVector<M> {
+    let mut result_vector = ComplexVector::zeros();
+
+    for (x, &y) in result_vector.0.iter_mut().zip(vector.iter()) {
+        *x = y;
+    }
+
+    result_vector
+}
+
+/// Error returned when a string can't be parsed as a complex vector.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseVectorError {
+    /// The string is not enclosed in square brackets.
+    MissingBrackets,
+    /// An entry is not a valid complex number.
+    Entry(ParseComplexError),
+    /// The number of entries doesn't match the size of the vector.
+    Length { expected: usize, found: usize },
+}
+
+#[cfg(feature = "std")]
+impl Display for ParseVectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseVectorError::MissingBrackets => write!(f, "vector must be enclosed in square brackets"),
+            ParseVectorError::Entry(e) => write!(f, "invalid vector entry: {}", e),
+            ParseVectorError::Length { expected, found } => write!(f, "expected {} entries, found {}", expected, found),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseVectorError {}
+
+/// Support for parsing complex vectors in the same `[a+bi, c+di]` form they
+/// are displayed in.
+#[cfg(feature = "std")]
+impl<const N: usize> FromStr for ComplexVector<N> {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s.trim()
+                     .strip_prefix('[')
+                     .and_then(|rest| rest.strip_suffix(']'))
+                     .ok_or(ParseVectorError::MissingBrackets)?;
+
+        let entries = if inner.trim().is_empty() {
+            Vec::new()
+        } else {
+            inner.split(',')
+                 .map(str::parse)
+                 .collect::<Result<Vec<Complex>, _>>()
+                 .map_err(ParseVectorError::Entry)?
+        };
+
+        if entries.len() != N {
+            return Err(ParseVectorError::Length { expected: N, found: entries.len() });
+        }
+
+        let mut vector = ComplexVector::zeros();
+        vector.0.copy_from_slice(&entries);
+        Ok(vector)
+    }
+}
+
 /// Coordinate-wise vector addition.
 fn add_vectors<const N: usize>(ComplexVector(lhs): ComplexVector<N>, ComplexVector(rhs): ComplexVector<N>) -> ComplexVector<N> {
-    let mut result_vector = [Complex::new(0.0, 0.0); N];
+    let mut result_vector = ComplexVector::zeros();
 
     for i in 0..N {
-        result_vector[i] = lhs[i] + rhs[i];
+        result_vector.0[i] = lhs[i] + rhs[i];
     };
 
-    ComplexVector(result_vector)
+    result_vector
 }
 
 /// Coordinate-wise complex scalar by complex vector product.
@@ -94,7 +570,7 @@ fn product_vector_scalar<const N: usize>(ComplexVector(vector): ComplexVector<N>
 
 /// Inner product of two complex vectors, defined as the sum of the product entry by entry
 /// of the conjugate vector by another vector.
-fn inner_product_vector<const N: usize>(ComplexVector(v1): ComplexVector<N>, ComplexVector(v2): ComplexVector<N>) -> Complex {
+fn inner_product_vector<const N: usize>(ComplexVector(v1): &ComplexVector<N>, ComplexVector(v2): &ComplexVector<N>) -> Complex {
     v1.iter()
       .zip(v2.iter())
       .map(|(&x1, &x2)| x1.conjugate() * x2)
@@ -147,7 +623,375 @@ mod tests {
         let v1 = ComplexVector([Complex::new(3.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
         let v2 = ComplexVector([Complex::new(2.0, 0.0), Complex::new(2.0, 0.0), Complex::new(-1.0, 0.0)]);
 
-        assert_eq!(v1.distance_to(v2), v2.distance_to(v1));
-        assert_eq!(v1.distance_to(v2), 11f64.sqrt());
+        assert_eq!(v1.distance_to(&v2), v2.distance_to(&v1));
+        assert_eq!(v1.distance_to(&v2), 11f64.sqrt());
+    }
+
+    #[test]
+    fn test_zeros_ones() {
+        assert_eq!(ComplexVector::<4>::zeros().norm(), 0.0);
+        assert_eq!(ComplexVector::<4>::ones().norm(), 2.0);
+        assert_eq!(ComplexVector::<3>::ones().norm(), 3f64.sqrt());
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        let e0 = ComplexVector([Complex::ONE, Complex::ZERO]);
+        let e1 = ComplexVector([Complex::ZERO, Complex::ONE]);
+        let v = ComplexVector([Complex::new(1.0 / 2f64.sqrt(), 0.0), Complex::new(0.0, 1.0 / 2f64.sqrt())]);
+
+        assert!(e0.is_orthogonal(&e1, 1e-10));
+        assert!(!e0.is_orthogonal(&v, 1e-10));
+        assert!(is_orthonormal_basis(&[e0, e1], 1e-10));
+        assert!(!is_orthonormal_basis(&[e0, v], 1e-10));
+        assert!(!is_orthonormal_basis(&[e0], 1e-10));
+        assert!(!is_orthonormal_basis(&[e0, e1 * Complex::new(2.0, 0.0)], 1e-10));
+    }
+
+    #[test]
+    fn test_is_zero() {
+        let tiny = ComplexVector([Complex::new(1e-15, 0.0), Complex::new(0.0, -1e-15)]);
+        let unit = ComplexVector([Complex::ZERO, Complex::I]);
+
+        assert!(ComplexVector::<3>::zeros().is_zero(1e-12));
+        assert!(tiny.is_zero(1e-12));
+        assert!(!unit.is_zero(1e-12));
+    }
+
+    #[test]
+    fn test_try_normalize() {
+        let v = ComplexVector([Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)]);
+        let (unit, norm) = v.try_normalize().unwrap();
+
+        assert_eq!(norm, 5.0);
+        assert!(unit.distance_to(&ComplexVector([Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)])) < 1e-12);
+        assert_eq!(v.normalize(), unit);
+        assert_eq!(ComplexVector::<2>::zeros().try_normalize(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot normalize the zero vector!")]
+    fn test_normalize_zero() {
+        ComplexVector::<2>::zeros().normalize();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_to_ket_string() {
+        let amplitude = Complex::new(1.0 / 2f64.sqrt(), 0.0);
+        let phi_plus = ComplexVector([amplitude, Complex::ZERO, Complex::new(1e-17, 0.0), amplitude]);
+        let phi_minus = ComplexVector([amplitude, Complex::ZERO, Complex::ZERO, -amplitude]);
+        let mixed = ComplexVector([Complex::new(0.0, -0.6), Complex::new(0.48, 0.64)]);
+
+        assert_eq!(phi_plus.to_ket_string(2, 1e-10), "0.707|00⟩ + 0.707|11⟩");
+        assert_eq!(phi_minus.to_ket_string(2, 1e-10), "0.707|00⟩ - 0.707|11⟩");
+        assert_eq!(mixed.to_ket_string(1, 1e-10), "-0.600i|0⟩ + (0.480+0.640i)|1⟩");
+        assert_eq!(ComplexVector::<4>::zeros().to_ket_string(2, 1e-10), "0");
+    }
+
+    #[test]
+    fn test_negate() {
+        let v = ComplexVector([Complex::new(6.0, -4.0), Complex::new(0.0, 3.0)]);
+        assert_eq!(v.negate(), -v);
+        assert_eq!(v.negate(), ComplexVector([Complex::new(-6.0, 4.0), Complex::new(0.0, -3.0)]));
+    }
+
+    #[test]
+    fn test_vector_product_scalar_left() {
+        let v = ComplexVector([Complex::new(6.0, 3.0), Complex::new(0.0, 0.0), Complex::new(5.0, 1.0), Complex::new(4.0, 0.0)]);
+        let c = Complex::new(3.0, 2.0);
+
+        assert_eq!(c * v, v * c);
+    }
+
+    #[test]
+    fn test_pad_truncate() {
+        let v = ComplexVector([Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5)]);
+        let padded = ComplexVector([Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5), Complex::ZERO, Complex::ZERO]);
+
+        assert_eq!(v.pad::<4>(), padded);
+        assert_eq!(padded.truncate::<2>(), v);
+        assert_eq!(v.pad::<2>(), v);
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let v1 = ComplexVector([Complex::new(6.0, -4.0), Complex::new(7.0, 3.0), Complex::new(4.2, -8.1)]);
+        let v2 = ComplexVector([Complex::new(16.0, 2.5), Complex::new(0.0, -7.0), Complex::new(6.0, 0.0)]);
+        let c = Complex::new(3.0, 2.0);
+
+        let mut sum = v1;
+        sum += v2;
+        assert_eq!(sum, v1 + v2);
+
+        let mut product = v1;
+        product *= c;
+        assert_eq!(product, v1 * c);
+    }
+
+    #[test]
+    fn test_from_array() {
+        let entries = [Complex::new(1.0, 2.0), Complex::I, Complex::ONE];
+        let v: ComplexVector<3> = entries.into();
+
+        assert_eq!(v, ComplexVector(entries));
+    }
+
+    #[test]
+    fn test_superposition() {
+        let state = ComplexVector::<4>::superposition(&[(0, Complex::ONE), (3, Complex::ONE)]).unwrap();
+        let amplitude = 1.0 / 2f64.sqrt();
+
+        assert!((state.0[0].abs() - amplitude).abs() < 1e-12);
+        assert!((state.0[3].abs() - amplitude).abs() < 1e-12);
+        assert_eq!(state.0[1], Complex::ZERO);
+        assert_eq!(state.0[2], Complex::ZERO);
+
+        assert_eq!(ComplexVector::<4>::superposition(&[(1, Complex::ONE), (1, -Complex::ONE)]), None);
+        assert_eq!(ComplexVector::<4>::superposition(&[]), None);
+    }
+
+    #[test]
+    fn test_weighted_sum() {
+        let e0 = ComplexVector([Complex::ONE, Complex::ZERO]);
+        let e1 = ComplexVector([Complex::ZERO, Complex::ONE]);
+        let v = ComplexVector::weighted_sum(&[(Complex::new(2.0, 0.0), e0), (Complex::new(3.0, 0.0), e1)]);
+
+        assert_eq!(v.0[0], Complex::new(2.0, 0.0));
+        assert_eq!(v.0[1], Complex::new(3.0, 0.0));
+        assert_eq!(ComplexVector::<2>::weighted_sum(&[]), ComplexVector::zeros());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str() {
+        let v = ComplexVector([Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(-2.5, -0.5)]);
+
+        assert_eq!("[1+0i, 0+1i, -2.5-0.5i]".parse(), Ok(v));
+        assert_eq!(v.to_string().parse(), Ok(v));
+        assert_eq!("[]".parse(), Ok(ComplexVector::<0>::zeros()));
+        assert_eq!(ComplexVector::<0>::zeros().to_string(), "[]");
+        assert_eq!(ComplexVector::<0>::zeros().to_string().parse(), Ok(ComplexVector::<0>::zeros()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str_negative_zero() {
+        let v = ComplexVector([Complex::ONE.conjugate(), Complex::new(-0.0, -0.0)]);
+        let parsed: ComplexVector<2> = v.to_string().parse().unwrap();
+
+        assert_eq!(v.to_string(), "[1-0i, -0-0i]");
+        assert_eq!(parsed, v);
+        assert!(parsed.0.iter().all(|c| c.imaginary.is_sign_negative()));
+        assert_eq!("[1+-0i, -0+-0i]".parse(), Ok(v));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str_errors() {
+        assert_eq!("[1+0i, 0+1i]".parse::<ComplexVector<3>>(), Err(ParseVectorError::Length { expected: 3, found: 2 }));
+        assert_eq!("1+0i, 0+1i".parse::<ComplexVector<2>>(), Err(ParseVectorError::MissingBrackets));
+        assert!(matches!("[1+0i, zero]".parse::<ComplexVector<2>>(), Err(ParseVectorError::Entry(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_indices_by_magnitude() {
+        let v = ComplexVector([Complex::new(0.1, 0.0), Complex::new(0.0, -0.8), Complex::new(0.3, 0.4),
+                               Complex::ZERO, Complex::new(-0.5, 0.0)]);
+
+        assert_eq!(v.indices_by_magnitude(), vec![1, 2, 4, 0, 3]);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let v = ComplexVector([Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(3.0, 0.0), Complex::new(4.0, 0.0)]);
+        let m = ComplexMatrix::new([[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)],
+                                    [Complex::new(3.0, 0.0), Complex::new(4.0, 0.0)]]);
+
+        assert_eq!(v.reshape::<2, 2>(), m);
+        assert_eq!(v.reshape::<2, 2>().flatten::<4>(), v);
+        assert_eq!(v.reshape::<1, 4>().flatten::<4>(), v);
+    }
+
+    #[test]
+    fn test_borrowed_inner_product() {
+        let v1 = ComplexVector([Complex::new(6.0, -4.0), Complex::new(7.0, 3.0), Complex::new(4.2, -8.1), Complex::new(0.0, -3.0)]);
+        let v2 = ComplexVector([Complex::new(16.0, 2.5), Complex::new(0.0, -7.0), Complex::new(6.0, 0.0), Complex::new(0.0, -4.0)]);
+
+        assert_eq!(v1.inner_product(&v2), v1 * v2);
+        assert_eq!(v2.inner_product(&v1), (v1 * v2).conjugate());
+    }
+
+    #[test]
+    fn test_sum() {
+        let v1 = ComplexVector([Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5)]);
+        let v2 = ComplexVector([Complex::new(0.0, -1.0), Complex::new(4.0, 4.0)]);
+        let v3 = ComplexVector([Complex::new(2.5, 0.0), Complex::new(0.0, -7.0)]);
+
+        assert_eq!([v1, v2, v3].iter().copied().sum::<ComplexVector<2>>(), v1 + v2 + v3);
+        assert_eq!(std::iter::empty().sum::<ComplexVector<2>>(), ComplexVector::zeros());
+    }
+
+    #[test]
+    fn test_concat_split() {
+        let v1 = ComplexVector([Complex::new(1.0, 0.0)]);
+        let v2 = ComplexVector([Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)]);
+        let joined = ComplexVector([Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)]);
+
+        assert_eq!(v1.concat::<2, 3>(v2), joined);
+        assert_eq!(joined.split::<1, 2>(), (v1, v2));
+        assert_eq!(joined.split::<3, 0>().0, joined);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot split a vector of size 3 into sizes 1 and 1.")]
+    fn test_split_mismatch() {
+        ComplexVector::<3>::ones().split::<1, 1>();
+    }
+
+    #[test]
+    fn test_hadamard_product() {
+        let v1 = ComplexVector([Complex::new(1.0, 2.0), Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)]);
+        let v2 = ComplexVector([Complex::new(1.0, -2.0), Complex::new(0.0, 1.0), Complex::new(-0.5, 4.0)]);
+        let expected = ComplexVector([Complex::new(5.0, 0.0), Complex::new(-1.0, 0.0), Complex::new(-1.5, 12.0)]);
+
+        assert_eq!(v1.hadamard_product(v2), expected);
+    }
+
+    #[test]
+    fn test_gram_matrix() {
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let plus = ComplexVector([Complex::new(s, 0.0), Complex::new(s, 0.0)]);
+        let minus = ComplexVector([Complex::new(s, 0.0), Complex::new(-s, 0.0)]);
+        let gram = gram_matrix::<2, 2>(&[plus, minus]);
+        assert!(gram.approx_eq(&ComplexMatrix::identity(), 1e-12));
+
+        let v = ComplexVector([Complex::new(1.0, 2.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)]);
+        let w = ComplexVector([Complex::new(0.0, 1.0), Complex::new(2.0, 0.0), Complex::new(1.0, 1.0)]);
+        let dependent = gram_matrix::<3, 3>(&[v, w, v * Complex::new(2.0, -1.0) + w]);
+        assert!(dependent.determinant().abs() < 1e-9);
+        assert_eq!(dependent[[0, 1]], v.inner_product(&w));
+    }
+
+    #[test]
+    fn test_dot_real() {
+        let v1 = ComplexVector([Complex::new(0.5, 0.0), Complex::new(-2.0, 0.0), Complex::new(3.0, 0.0)]);
+        let v2 = ComplexVector([Complex::new(4.0, 0.0), Complex::new(1.5, 0.0), Complex::new(2.0, 0.0)]);
+
+        assert_eq!(v1.dot_real(&v2), 5.0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is not real")]
+    fn test_dot_real_complex() {
+        let v1 = ComplexVector([Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+        let v2 = ComplexVector([Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)]);
+
+        v1.dot_real(&v2);
+    }
+
+    #[test]
+    fn test_argmax_probability() {
+        // A Grover-like state, where the amplitude of the marked |10> dominates.
+        let small = Complex::new(0.05, 0.0);
+        let state = ComplexVector([small, -small, Complex::new(-0.99, 0.0), small]);
+        assert_eq!(state.argmax_probability(), 2);
+
+        let tied = ComplexVector([Complex::new(0.0, 0.5), Complex::new(0.5, 0.0), Complex::new(-0.5, 0.0), Complex::ZERO]);
+        assert_eq!(tied.argmax_probability(), 0);
+    }
+
+    #[test]
+    fn test_borrowed_distance_and_sub() {
+        let v = ComplexVector([Complex::new(1.0, 1.0), Complex::new(0.0, -2.0)]);
+        let w = ComplexVector([Complex::new(1.0, 0.0), Complex::new(3.0, 2.0)]);
+
+        let first = v.distance_to(&w);
+        let second = v.distance_to(&w);
+        assert_eq!(first, second);
+        assert_eq!(first, 26f64.sqrt());
+
+        let (v_ref, w_ref) = (&v, &w);
+        assert_eq!(v_ref - w_ref, v - w);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_gram_schmidt() {
+        let v1 = ComplexVector([Complex::new(1.0, 0.0), Complex::new(1.0, 0.0), Complex::ZERO]);
+        let v2 = ComplexVector([Complex::new(0.0, 1.0), Complex::ZERO, Complex::new(2.0, 0.0)]);
+        let v3 = ComplexVector([Complex::ZERO, Complex::new(3.0, 0.0), Complex::new(1.0, -1.0)]);
+
+        let basis = gram_schmidt(&[v1, v2, v3]).unwrap();
+        assert!(is_orthonormal_basis(&basis, 1e-12));
+        assert!(basis[0].distance_to(&v1.normalize()) < 1e-12);
+
+        assert_eq!(gram_schmidt(&[v1, v2, v1 + v2]), None);
+    }
+
+    #[test]
+    fn test_project_onto() {
+        let v = ComplexVector([Complex::ONE, Complex::ONE]);
+        let e0 = ComplexVector([Complex::new(2.0, 0.0), Complex::ZERO]);
+        let e1 = ComplexVector([Complex::ZERO, Complex::I]);
+
+        assert_eq!(v.project_onto(&e0), ComplexVector([Complex::ONE, Complex::ZERO]));
+        assert_eq!(ComplexVector([Complex::ONE, Complex::ZERO]).project_onto(&e1), ComplexVector::zeros());
+        assert_eq!(v.project_onto(&ComplexVector::zeros()), ComplexVector::zeros());
+    }
+
+    #[test]
+    fn test_scalar_broadcast() {
+        let v = ComplexVector([Complex::new(1.0, 2.0), Complex::new(-3.0, 0.0), Complex::new(0.0, -1.0)]);
+
+        assert_eq!(v + Complex::ONE, ComplexVector([Complex::new(2.0, 2.0), Complex::new(-2.0, 0.0), Complex::new(1.0, -1.0)]));
+        assert_eq!(v - Complex::I, ComplexVector([Complex::new(1.0, 1.0), Complex::new(-3.0, -1.0), Complex::new(0.0, -2.0)]));
+        assert_eq!(v + Complex::ONE - Complex::ONE, v);
+        assert_eq!(v + ComplexVector::ones(), v + Complex::ONE);
+    }
+
+    #[test]
+    fn test_max_min_abs() {
+        let v = ComplexVector([Complex::new(3.0, 4.0), Complex::new(0.0, -1.0), Complex::new(-2.0, 0.0)]);
+
+        assert_eq!(v.max_abs(), 5.0);
+        assert_eq!(v.min_abs(), 1.0);
+        assert_eq!(ComplexVector::<0>::zeros().max_abs(), 0.0);
+        assert_eq!(ComplexVector::<0>::zeros().min_abs(), 0.0);
+    }
+
+    #[test]
+    fn test_rotate_indices() {
+        let (a, b, c) = (Complex::new(1.0, 0.0), Complex::new(0.0, 2.0), Complex::new(-3.0, 1.0));
+        let v = ComplexVector([a, b, c]);
+
+        assert_eq!(v.rotate_indices(1), ComplexVector([c, a, b]));
+        assert_eq!(v.rotate_indices(5), ComplexVector([b, c, a]));
+        assert_eq!(v.rotate_indices(3), v);
+        assert_eq!(ComplexVector::<0>::zeros().rotate_indices(2), ComplexVector::zeros());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_expectation_paulis() {
+        use crate::utils::gates::{pauli_x, pauli_y, pauli_z};
+        use crate::utils::measurement::expectation;
+
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let states = [ComplexVector([Complex::ONE, Complex::ZERO]),
+                      ComplexVector([Complex::ZERO, Complex::ONE]),
+                      ComplexVector([Complex::new(s, 0.0), Complex::new(s, 0.0)]),
+                      ComplexVector([Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)])];
+
+        for state in &states {
+            assert!((state.expectation_x() - expectation(state, &pauli_x())).abs() < 1e-12);
+            assert!((state.expectation_y() - expectation(state, &pauli_y())).abs() < 1e-12);
+            assert!((state.expectation_z() - expectation(state, &pauli_z())).abs() < 1e-12);
+        }
+
+        assert!((states[2].expectation_x() - 1.0).abs() < 1e-12);
+        assert_eq!(states[1].expectation_z(), -1.0);
     }
 }
@@ -0,0 +1,48 @@
+//! Serde support for complex vectors, as arrays of complex numbers. The size
+//! is checked when deserializing.
+
+use std::convert::TryFrom;
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+use crate::utils::complex_number::Complex;
+use super::ComplexVector;
+
+impl<const N: usize> Serialize for ComplexVector<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for ComplexVector<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = Vec::<Complex>::deserialize(deserializer)?;
+        let found = entries.len();
+
+        <[Complex; N]>::try_from(entries)
+            .map(ComplexVector)
+            .map_err(|_| D::Error::invalid_length(found, &format!("a vector of {} complex numbers", N).as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let v = ComplexVector([Complex::new(0.6, -1.5), Complex::new(0.0, 0.8)]);
+        let json = serde_json::to_string(&v).unwrap();
+
+        let parsed: ComplexVector<2> = serde_json::from_str(&json).unwrap();
+        assert!(parsed.distance_to(&v) < 1e-12);
+    }
+
+    #[test]
+    fn test_wrong_size() {
+        let json = r#"[{"real": 1.0, "imaginary": 0.0}]"#;
+
+        assert!(serde_json::from_str::<ComplexVector<2>>(json).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! A small evaluator of arithmetic expressions over complex numbers, such as
+//! `(1+2i)*(3-i)/2`.
+//!
+//! The grammar, from lowest to highest precedence:
+//!
+//! ```text
+//! expression := term (('+' | '-') term)*
+//! term       := factor (('*' | '/') factor)*
+//! factor     := ('+' | '-') factor | '(' expression ')' | literal
+//! ```
+//!
+//! Literals are real numbers, optionally followed by `i`, or `i` alone. They
+//! are parsed with `FromStr for Complex`.
+
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::utils::complex_number::{Complex, ParseComplexError};
+
+/// Error returned when an expression can't be evaluated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// A literal is not a valid complex number.
+    Literal(ParseComplexError),
+    /// An unexpected character, at the given byte offset.
+    Unexpected { found: char, position: usize },
+    /// The expression ended where an operand or a `)` was expected.
+    UnexpectedEnd,
+    /// A division by zero.
+    DivisionByZero,
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Literal(err) => write!(f, "{}", err),
+            EvalError::Unexpected { found, position } => write!(f, "unexpected '{}' at position {}", found, position),
+            EvalError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+impl From<ParseComplexError> for EvalError {
+    fn from(err: ParseComplexError) -> Self {
+        EvalError::Literal(err)
+    }
+}
+
+/// Evaluates an arithmetic expression with `+ - * /`, parentheses and complex
+/// literals. Whitespace is ignored.
+pub fn eval_complex(expr: &str) -> Result<Complex, EvalError> {
+    let mut parser = Parser { input: expr, position: 0 };
+    let value = parser.expression()?;
+
+    match parser.peek() {
+        Some(c) => Err(EvalError::Unexpected { found: c, position: parser.position }),
+        None => Ok(value),
+    }
+}
+
+/// Recursive descent parser, evaluating as it goes.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    /// The next character that isn't whitespace, without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.input[self.position..];
+        let trimmed = rest.trim_start();
+        self.position += rest.len() - trimmed.len();
+
+        trimmed.chars().next()
+    }
+
+    fn expression(&mut self) -> Result<Complex, EvalError> {
+        let mut value = self.term()?;
+
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.position += 1;
+            let rhs = self.term()?;
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<Complex, EvalError> {
+        let mut value = self.factor()?;
+
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.position += 1;
+            let rhs = self.factor()?;
+
+            value = if op == '*' {
+                value * rhs
+            } else if rhs == Complex::ZERO {
+                return Err(EvalError::DivisionByZero);
+            } else {
+                value / rhs
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn factor(&mut self) -> Result<Complex, EvalError> {
+        match self.peek() {
+            Some('+') => {
+                self.position += 1;
+                self.factor()
+            },
+            Some('-') => {
+                self.position += 1;
+                Ok(-self.factor()?)
+            },
+            Some('(') => {
+                self.position += 1;
+                let value = self.expression()?;
+
+                match self.peek() {
+                    Some(')') => {
+                        self.position += 1;
+                        Ok(value)
+                    },
+                    Some(c) => Err(EvalError::Unexpected { found: c, position: self.position }),
+                    None => Err(EvalError::UnexpectedEnd),
+                }
+            },
+            Some(c) if c.is_ascii_digit() || c == '.' || c == 'i' => self.literal(),
+            Some(c) => Err(EvalError::Unexpected { found: c, position: self.position }),
+            None => Err(EvalError::UnexpectedEnd),
+        }
+    }
+
+    /// A number with an optional exponent and `i` suffix, or `i` alone.
+    fn literal(&mut self) -> Result<Complex, EvalError> {
+        let bytes = self.input.as_bytes();
+        let start = self.position;
+        let mut end = start;
+
+        while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+            end += 1;
+        }
+
+        // An exponent only if digits follow, possibly after a sign.
+        if end > start && end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+            let digits = if matches!(bytes.get(end + 1), Some(b'+' | b'-')) { end + 2 } else { end + 1 };
+
+            if bytes.get(digits).is_some_and(u8::is_ascii_digit) {
+                end = digits;
+
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+            }
+        }
+
+        if end < bytes.len() && bytes[end] == b'i' {
+            end += 1;
+        }
+
+        self.position = end;
+        Ok(self.input[start..end].parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(eval_complex("(1+2i)*(3-i)"), Ok(Complex::new(5.0, 5.0)));
+        assert_eq!(eval_complex("1/i"), Ok(Complex::new(0.0, -1.0)));
+        assert_eq!(eval_complex("1 + 2 * 3i"), Ok(Complex::new(1.0, 6.0)));
+        assert_eq!(eval_complex("-(2 - 1.5e1i) / 2"), Ok(Complex::new(-1.0, 7.5)));
+        assert_eq!(eval_complex("2 - 1 - 1"), Ok(Complex::ZERO));
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        assert_eq!(eval_complex("1 / (i - i)"), Err(EvalError::DivisionByZero));
+        assert_eq!(eval_complex("(1 + 2"), Err(EvalError::UnexpectedEnd));
+        assert_eq!(eval_complex("1 + x"), Err(EvalError::Unexpected { found: 'x', position: 4 }));
+        assert_eq!(eval_complex("2 3"), Err(EvalError::Unexpected { found: '3', position: 2 }));
+        assert!(matches!(eval_complex("1.2.3"), Err(EvalError::Literal(_))));
+    }
+}
@@ -0,0 +1,219 @@
+//! Common quantum gates, as complex matrices.
+//!
+//! Multi-qubit gates order the basis with qubit 0 as the most significant
+//! bit, so the two-qubit basis is |q0 q1>: |00>, |01>, |10>, |11>.
+
+use rand::Rng;
+
+use crate::utils::complex_number::{Complex, Polar};
+use crate::utils::complex_vector::{ComplexVector, gram_schmidt};
+use crate::utils::complex_matrix::ComplexMatrix;
+use crate::utils::bits;
+
+/// The Hadamard gate.
+pub fn hadamard() -> ComplexMatrix<2, 2> {
+    hadamard_n(1)
+}
+
+/// The Hadamard gate on `n` qubits, where N must be 2^n. Entry (r, c) is
+/// (-1)^(r·c) / √N, with r·c the number of bits set in both indices.
+pub fn hadamard_n<const N: usize>(n: usize) -> ComplexMatrix<N, N> {
+    if N != 1 << n {
+        panic!("The Hadamard gate on {} qubits is {}x{}, not {}x{}.", n, 1 << n, 1 << n, N, N);
+    }
+
+    let entry = 1.0 / (N as f64).sqrt();
+    let mut m = ComplexMatrix::identity();
+
+    for r in 0..N {
+        for c in 0..N {
+            let sign = if (r & c).count_ones() % 2 == 0 { 1.0 } else { -1.0 };
+            m[[r, c]] = Complex::new(sign * entry, 0.0);
+        }
+    }
+
+    m
+}
+
+/// The Pauli X (NOT) gate.
+pub fn pauli_x() -> ComplexMatrix<2, 2> {
+    ComplexMatrix::new([[Complex::ZERO, Complex::ONE],
+                        [Complex::ONE, Complex::ZERO]])
+}
+
+/// The Pauli Y gate.
+pub fn pauli_y() -> ComplexMatrix<2, 2> {
+    ComplexMatrix::new([[Complex::ZERO, -Complex::I],
+                        [Complex::I, Complex::ZERO]])
+}
+
+/// The Pauli Z gate.
+pub fn pauli_z() -> ComplexMatrix<2, 2> {
+    ComplexMatrix::new([[Complex::ONE, Complex::ZERO],
+                        [Complex::ZERO, -Complex::ONE]])
+}
+
+/// The phase shift gate, multiplying |1> by e^(iθ).
+pub fn phase(theta: f64) -> ComplexMatrix<2, 2> {
+    ComplexMatrix::from_diagonal([Complex::ONE, Complex::from(Polar(1.0, theta))])
+}
+
+/// The S gate, a phase shift by π/2. It is the square root of Z.
+pub fn s_gate() -> ComplexMatrix<2, 2> {
+    phase(std::f64::consts::FRAC_PI_2)
+}
+
+/// The T gate, a phase shift by π/4. It is the square root of S.
+pub fn t_gate() -> ComplexMatrix<2, 2> {
+    phase(std::f64::consts::FRAC_PI_4)
+}
+
+/// A random unitary, obtained by orthonormalizing the columns of a matrix
+/// with standard Gaussian entries. Useful to test gate identities beyond the
+/// fixed gates.
+pub fn random_unitary<const N: usize>(rng: &mut impl Rng) -> ComplexMatrix<N, N> {
+    loop {
+        let columns: Vec<ComplexVector<N>> = (0..N).map(|_| {
+            let mut column = ComplexVector::zeros();
+
+            for entry in column.0.iter_mut() {
+                *entry = Complex::new(gaussian(rng), gaussian(rng));
+            }
+
+            column
+        }).collect();
+
+        // Dependent columns have probability zero, but retry just in case.
+        if let Some(basis) = gram_schmidt(&columns) {
+            let mut m = ComplexMatrix::identity();
+
+            for (c, column) in basis.iter().enumerate() {
+                for (r, &entry) in column.0.iter().enumerate() {
+                    m[[r, c]] = entry;
+                }
+            }
+
+            return m;
+        }
+    }
+}
+
+/// Sample of the standard normal distribution, with the Box–Muller transform.
+fn gaussian(rng: &mut impl Rng) -> f64 {
+    // Shift the first sample into (0, 1], so the logarithm is finite.
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+
+    f64::sqrt(-2.0 * u1.ln()) * f64::cos(std::f64::consts::TAU * u2)
+}
+
+/// Controlled version of `gate`, with qubit 0 as control and qubit 1 as target.
+pub fn controlled(gate: ComplexMatrix<2, 2>) -> ComplexMatrix<4, 4> {
+    let zeros = || ComplexMatrix::new([[Complex::ZERO; 2]; 2]);
+    ComplexMatrix::from_blocks(ComplexMatrix::identity(), zeros(), zeros(), gate)
+}
+
+/// Controlled version of `gate` on two qubits, with the given control and
+/// target qubits. These must be 0 and 1, in either order.
+pub fn controlled_on(gate: ComplexMatrix<2, 2>, control: usize, target: usize) -> ComplexMatrix<4, 4> {
+    if control > 1 || target > 1 || control == target {
+        panic!("Control and target must be different qubits among 0 and 1.");
+    }
+
+    let bit = |index: usize, qubit: usize| bits::bit(index, qubit, 2);
+    let mut m = ComplexMatrix::new([[Complex::ZERO; 4]; 4]);
+
+    for row in 0..4 {
+        for col in 0..4 {
+            m[[row, col]] = match (bit(row, control), bit(col, control)) {
+                (false, false) if row == col => Complex::ONE,
+                (true, true) => gate[[bit(row, target) as usize, bit(col, target) as usize]],
+                _ => Complex::ZERO,
+            };
+        }
+    }
+
+    m
+}
+
+/// The controlled Z gate.
+pub fn cz() -> ComplexMatrix<4, 4> {
+    controlled(pauli_z())
+}
+
+/// The controlled phase shift gate, multiplying |11> by e^(iθ).
+pub fn cphase(theta: f64) -> ComplexMatrix<4, 4> {
+    controlled(phase(theta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hadamard() {
+        let entry = Complex::new(1.0 / 2f64.sqrt(), 0.0);
+        assert_eq!(hadamard(), ComplexMatrix::new([[entry, entry], [entry, -entry]]));
+        assert_eq!(hadamard_n::<4>(2)[[3, 3]], Complex::new(0.5, 0.0));
+        assert_eq!(hadamard_n::<4>(2)[[1, 3]], Complex::new(-0.5, 0.0));
+    }
+
+    #[test]
+    fn test_controlled() {
+        // |10> <-> |11>
+        assert_eq!(controlled(pauli_x()), ComplexMatrix::permutation([0, 1, 3, 2]));
+        assert_eq!(controlled_on(pauli_x(), 0, 1), controlled(pauli_x()));
+    }
+
+    #[test]
+    fn test_controlled_on_second_qubit() {
+        // |01> <-> |11>
+        assert_eq!(controlled_on(pauli_x(), 1, 0), ComplexMatrix::permutation([0, 3, 2, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_controlled_on_same_qubit() {
+        controlled_on(pauli_x(), 1, 1);
+    }
+
+    #[test]
+    fn test_paulis() {
+        assert_eq!(&pauli_x() * &pauli_y(), pauli_z() * Complex::I);
+        assert_eq!(&pauli_y() * &pauli_y(), ComplexMatrix::identity());
+    }
+
+    #[test]
+    fn test_phase() {
+        assert!(phase(std::f64::consts::PI).approx_eq(&pauli_z(), 1e-12));
+        assert_eq!(phase(0.0), ComplexMatrix::identity());
+    }
+
+    #[test]
+    fn test_s_t_gates() {
+        assert!((t_gate() * t_gate()).approx_eq(&s_gate(), 1e-12));
+        assert!((s_gate() * s_gate()).approx_eq(&pauli_z(), 1e-12));
+        assert!(s_gate().is_unitary(1e-12));
+        assert!(t_gate().is_unitary(1e-12));
+    }
+
+    #[test]
+    fn test_random_unitary() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for _ in 0..5 {
+            assert!(random_unitary::<2>(&mut rng).is_unitary(1e-9));
+            assert!(random_unitary::<4>(&mut rng).is_unitary(1e-9));
+        }
+    }
+
+    #[test]
+    fn test_controlled_phase() {
+        let expected = ComplexMatrix::from_diagonal([Complex::ONE, Complex::ONE, Complex::ONE, -Complex::ONE]);
+
+        assert_eq!(cz(), expected);
+        assert!(cphase(std::f64::consts::PI).approx_eq(&cz(), 1e-12));
+    }
+}
@@ -0,0 +1,146 @@
+//! Gaussian integers, the complex numbers a + bi with integer parts. Their
+//! arithmetic is exact, unlike that of `Complex`, which makes them suitable
+//! for divisibility and primality exercises.
+
+use core::ops::{Add, Mul};
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::fmt::{Display, Formatter, Result};
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+use crate::utils::complex_number::Complex;
+
+/// The Gaussian integer `re + im·i`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GaussianInt {
+    pub re: i64,
+    pub im: i64,
+}
+
+impl GaussianInt {
+    pub fn new(re: i64, im: i64) -> Self {
+        GaussianInt { re, im }
+    }
+
+    /// The norm `re² + im²`, which is multiplicative: N(zw) = N(z)·N(w).
+    pub fn norm(self) -> i64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// The same number as a (floating point) complex number.
+    pub fn to_complex(self) -> Complex {
+        Complex::new(self.re as f64, self.im as f64)
+    }
+}
+
+impl Add for GaussianInt {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        GaussianInt::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Mul for GaussianInt {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        GaussianInt::new(self.re * other.re - self.im * other.im,
+                         self.re * other.im + self.im * other.re)
+    }
+}
+
+/// Error returned when a string can't be parsed as a Gaussian integer.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseGaussianIntError(String);
+
+#[cfg(feature = "std")]
+impl Display for ParseGaussianIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "invalid Gaussian integer: \"{}\"", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseGaussianIntError {}
+
+// Support for parsing Gaussian integers in the same `a+bi` form as complex
+// numbers, but with integer parts. Either part may be omitted, as in `3`,
+// `-2i` or `i`.
+#[cfg(feature = "std")]
+impl FromStr for GaussianInt {
+    type Err = ParseGaussianIntError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let trimmed: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let error = || ParseGaussianIntError(s.to_string());
+
+        // The sign separating both parts, skipping a leading sign.
+        let split = trimmed.get(1..).and_then(|rest| rest.rfind(['+', '-'])).map(|k| k + 1);
+
+        match (trimmed.strip_suffix('i'), split) {
+            (None, _) => {
+                let re = trimmed.parse().map_err(|_| error())?;
+                Ok(Self::new(re, 0))
+            },
+            (Some(imaginary), None) => {
+                let im = parse_imaginary(imaginary).ok_or_else(error)?;
+                Ok(Self::new(0, im))
+            },
+            (Some(imaginary), Some(k)) => {
+                let re = trimmed[..k].parse().map_err(|_| error())?;
+                let im = parse_imaginary(&imaginary[k..]).ok_or_else(error)?;
+                Ok(Self::new(re, im))
+            },
+        }
+    }
+}
+
+/// Parses the coefficient of an imaginary part, where a lone sign stands for 1.
+#[cfg(feature = "std")]
+fn parse_imaginary(s: &str) -> Option<i64> {
+    match s {
+        "" | "+" => Some(1),
+        "-" => Some(-1),
+        _ => s.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str() {
+        assert_eq!("3+2i".parse(), Ok(GaussianInt::new(3, 2)));
+        assert_eq!(" -4 - i ".parse(), Ok(GaussianInt::new(-4, -1)));
+        assert_eq!("7".parse(), Ok(GaussianInt::new(7, 0)));
+        assert_eq!("-5i".parse(), Ok(GaussianInt::new(0, -5)));
+        assert!("1.5+2i".parse::<GaussianInt>().is_err());
+        assert!("3+2j".parse::<GaussianInt>().is_err());
+        assert!("".parse::<GaussianInt>().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let z = GaussianInt::new(3, 2);
+        let w = GaussianInt::new(1, -4);
+
+        assert_eq!(z + w, GaussianInt::new(4, -2));
+        assert_eq!(z * w, GaussianInt::new(11, -10));
+        assert_eq!((z * w).norm(), z.norm() * w.norm());
+
+        // 2 isn't prime among the Gaussian integers: 2 = (1+i)(1-i).
+        assert_eq!(GaussianInt::new(1, 1) * GaussianInt::new(1, -1), GaussianInt::new(2, 0));
+    }
+
+    #[test]
+    fn test_to_complex() {
+        assert_eq!(GaussianInt::new(3, -2).to_complex(), Complex::new(3.0, -2.0));
+        assert_eq!(GaussianInt::new(0, 1).to_complex(), Complex::I);
+    }
+}
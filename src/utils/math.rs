@@ -0,0 +1,34 @@
+//! Float functions that `core` lacks. They come from `std` when it is
+//! available, and from `libm` otherwise, so the arithmetic modules build
+//! without `std`.
+
+#[cfg(feature = "std")]
+mod imp {
+    pub fn sqrt(x: f64) -> f64 { x.sqrt() }
+    pub fn hypot(x: f64, y: f64) -> f64 { x.hypot(y) }
+    pub fn sin(x: f64) -> f64 { x.sin() }
+    pub fn cos(x: f64) -> f64 { x.cos() }
+    pub fn atan2(y: f64, x: f64) -> f64 { y.atan2(x) }
+    pub fn exp(x: f64) -> f64 { x.exp() }
+    pub fn ln(x: f64) -> f64 { x.ln() }
+    pub fn round(x: f64) -> f64 { x.round() }
+    pub fn powi(x: f64, n: i32) -> f64 { x.powi(n) }
+    pub fn copysign(x: f64, sign: f64) -> f64 { x.copysign(sign) }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub use libm::{sqrt, hypot, sin, cos, atan2, exp, round, copysign};
+    pub fn ln(x: f64) -> f64 { libm::log(x) }
+    pub fn powi(x: f64, n: i32) -> f64 { libm::pow(x, n as f64) }
+}
+
+pub(crate) use self::imp::*;
+
+/// The remainder of `x` divided by `y` with the sign of `y`, i.e. in [0, y)
+/// for positive `y`, like `f64::rem_euclid`.
+pub(crate) fn rem_euclid(x: f64, y: f64) -> f64 {
+    let r = x % y;
+
+    if r < 0.0 { r + y.abs() } else { r }
+}
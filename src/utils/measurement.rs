@@ -0,0 +1,244 @@
+//! Measurement of quantum states.
+
+use rand::Rng;
+
+use crate::utils::complex_number::Complex;
+use crate::utils::complex_vector::ComplexVector;
+use crate::utils::complex_matrix::ComplexMatrix;
+use crate::utils::bits;
+use crate::utils::simulation::check_register;
+
+/// Probability of observing each basis state when measuring `state`. The
+/// state doesn't need to be normalized.
+pub fn probabilities<const N: usize>(state: &ComplexVector<N>) -> [f64; N] {
+    let total: f64 = state.0.iter().map(|c| c.norm_sqr()).sum();
+    state.0.map(|c| c.norm_sqr() / total)
+}
+
+/// Samples the index of the basis state observed when measuring `state`.
+pub fn measure<const N: usize>(state: &ComplexVector<N>, rng: &mut impl Rng) -> usize {
+    Distribution::from_state(state).sample(rng)
+}
+
+/// The state after observing the basis state `index`, which is that basis
+/// state itself.
+pub fn collapse<const N: usize>(index: usize) -> ComplexVector<N> {
+    let mut state = ComplexVector::zeros();
+    state.0[index] = Complex::ONE;
+    state
+}
+
+/// Measures `state`, returning the observed index together with the state it
+/// collapses to.
+pub fn measure_and_collapse<const N: usize>(state: &ComplexVector<N>, rng: &mut impl Rng) -> (usize, ComplexVector<N>) {
+    let index = measure(state, rng);
+    (index, collapse(index))
+}
+
+/// Measures only the `qubit` of the `state` of a register of `n_qubits`.
+/// Returns whether it was observed as 1, together with the state it
+/// collapses to: the amplitudes consistent with the outcome, renormalized.
+///
+/// Panics if N isn't 2^n_qubits or the qubit is out of the register.
+pub fn measure_qubit<const N: usize>(state: &ComplexVector<N>, qubit: usize, n_qubits: usize, rng: &mut impl Rng) -> (bool, ComplexVector<N>) {
+    check_register::<N>(&[qubit], n_qubits);
+
+    let probabilities = probabilities(state);
+    let probability_one: f64 = (0..N).filter(|&i| bits::bit(i, qubit, n_qubits))
+                                     .map(|i| probabilities[i])
+                                     .sum();
+
+    let outcome = rng.gen::<f64>() < probability_one;
+    let mut collapsed = *state;
+
+    for (i, amplitude) in collapsed.0.iter_mut().enumerate() {
+        if bits::bit(i, qubit, n_qubits) != outcome {
+            *amplitude = Complex::ZERO;
+        }
+    }
+
+    (outcome, collapsed.normalize())
+}
+
+/// Expected value ⟨ψ|A|ψ⟩ of the observable `A` on the state `|ψ⟩`. The
+/// observable is assumed to be Hermitian, so only the real part is kept.
+pub fn expectation<const N: usize>(state: &ComplexVector<N>, observable: &ComplexMatrix<N, N>) -> f64 {
+    (*state * (observable * *state)).real
+}
+
+/// Projective measurement of `state` with `projector`. Returns the probability
+/// ⟨ψ|P|ψ⟩ of the outcome, and the post-measurement state P|ψ⟩/‖P|ψ⟩‖.
+///
+/// If the outcome is impossible there is no post-measurement state, so the
+/// zero vector is returned along with a probability of 0.
+pub fn project<const N: usize>(state: &ComplexVector<N>, projector: &ComplexMatrix<N, N>) -> (f64, ComplexVector<N>) {
+    let probability = expectation(state, projector);
+    let projected = projector * *state;
+
+    match projected.try_normalize() {
+        Some((post_state, _)) => (probability, post_state),
+        None => (0.0, ComplexVector::zeros()),
+    }
+}
+
+/// Probability distribution of the outcomes of measuring a state in the
+/// computational basis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    /// Probability of each basis state, adding up to 1.
+    pub probabilities: Vec<f64>,
+}
+
+impl Distribution {
+    /// The distribution of measuring `state`, which doesn't need to be
+    /// normalized.
+    pub fn from_state<const N: usize>(state: &ComplexVector<N>) -> Self {
+        Distribution { probabilities: probabilities(state).to_vec() }
+    }
+
+    /// Shannon entropy in bits, -Σ p·log₂(p). Outcomes of probability zero
+    /// don't contribute.
+    pub fn shannon_entropy(&self) -> f64 {
+        -self.probabilities.iter()
+                           .filter(|&&p| p > 0.0)
+                           .map(|&p| p * p.log2())
+                           .sum::<f64>()
+    }
+
+    /// Samples an outcome.
+    pub fn sample(&self, rng: &mut impl Rng) -> usize {
+        let sample: f64 = rng.gen();
+        let mut cumulative = 0.0;
+
+        for (index, &probability) in self.probabilities.iter().enumerate() {
+            cumulative += probability;
+
+            if sample < cumulative {
+                return index;
+            }
+        }
+
+        // Rounding errors may leave the total just below 1, so fall back to the
+        // last possible outcome.
+        self.probabilities.iter().rposition(|&p| p > 0.0).unwrap_or(0)
+    }
+
+    /// The most likely outcome. On ties the first one wins.
+    pub fn most_likely(&self) -> usize {
+        (0..self.probabilities.len()).fold(0, |best, i| {
+            if self.probabilities[i] > self.probabilities[best] { i } else { best }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_expectation() {
+        let zero = ComplexVector([Complex::ONE, Complex::ZERO]);
+        let one = ComplexVector([Complex::ZERO, Complex::ONE]);
+        let z = ComplexMatrix::from_diagonal([Complex::ONE, -Complex::ONE]);
+
+        assert_eq!(expectation(&zero, &z), 1.0);
+        assert_eq!(expectation(&one, &z), -1.0);
+    }
+
+    #[test]
+    fn test_project() {
+        let amplitude = Complex::new(1.0 / 2f64.sqrt(), 0.0);
+        let plus = ComplexVector([amplitude, amplitude]);
+        let zero = ComplexVector([Complex::ONE, Complex::ZERO]);
+        let projector_zero = ComplexMatrix::from_diagonal([Complex::ONE, Complex::ZERO]);
+        let projector_one = ComplexMatrix::from_diagonal([Complex::ZERO, Complex::ONE]);
+
+        let (probability, post_state) = project(&plus, &projector_zero);
+        assert!((probability - 0.5).abs() < 1e-12);
+        assert!(post_state.distance_to(&zero) < 1e-12);
+
+        let (probability, post_state) = project(&zero, &projector_one);
+        assert_eq!(probability, 0.0);
+        assert_eq!(post_state, ComplexVector::zeros());
+    }
+
+    #[test]
+    fn test_probabilities() {
+        let state = ComplexVector([Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)]);
+        let [p0, p1] = probabilities(&state);
+
+        assert!((p0 - 0.36).abs() < 1e-12);
+        assert!((p1 - 0.64).abs() < 1e-12);
+        assert_eq!(probabilities(&(state * Complex::new(2.0, 0.0))), probabilities(&state));
+    }
+
+    #[test]
+    fn test_measure_and_collapse() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let amplitude = Complex::new(0.5, 0.0);
+        let state = ComplexVector([amplitude, Complex::ZERO, amplitude, -amplitude * Complex::I, amplitude]);
+
+        for _ in 0..20 {
+            let (index, collapsed) = measure_and_collapse(&state, &mut rng);
+
+            assert_ne!(index, 1);
+            assert_eq!(collapsed, collapse(index));
+            assert_eq!(collapsed.0[index], Complex::ONE);
+        }
+    }
+
+    #[test]
+    fn test_measure_qubit_bell() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let amplitude = Complex::new(1.0 / 2f64.sqrt(), 0.0);
+        let bell = ComplexVector([amplitude, Complex::ZERO, Complex::ZERO, amplitude]);
+        let mut outcomes = [0; 2];
+
+        for _ in 0..20 {
+            let (first, collapsed) = measure_qubit(&bell, 0, 2, &mut rng);
+            let expected = collapse(if first { 0b11 } else { 0b00 });
+            outcomes[first as usize] += 1;
+
+            assert!(collapsed.distance_to(&expected) < 1e-12);
+
+            // The second qubit now always agrees with the first.
+            let (second, _) = measure_qubit(&collapsed, 1, 2, &mut rng);
+            assert_eq!(second, first);
+        }
+
+        assert!(outcomes.iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Qubit 2 is out of a register of 2 qubits.")]
+    fn test_measure_qubit_out_of_register() {
+        let mut rng = StdRng::seed_from_u64(7);
+        measure_qubit(&collapse::<4>(0), 2, 2, &mut rng);
+    }
+
+    #[test]
+    #[should_panic(expected = "A register of 3 qubits has 8 basis states, not 4.")]
+    fn test_measure_qubit_wrong_size() {
+        let mut rng = StdRng::seed_from_u64(7);
+        measure_qubit(&collapse::<4>(0), 0, 3, &mut rng);
+    }
+
+    #[test]
+    fn test_distribution() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let uniform = Distribution::from_state(&ComplexVector([Complex::new(0.5, 0.0); 4]));
+        assert!((uniform.shannon_entropy() - 2.0).abs() < 1e-12);
+
+        let state = ComplexVector([Complex::ZERO, Complex::new(0.6, 0.0), Complex::ZERO, Complex::new(0.0, 0.8)]);
+        let distribution = Distribution::from_state(&state);
+        for _ in 0..50 {
+            let outcome = distribution.sample(&mut rng);
+            assert!(distribution.probabilities[outcome] > 0.0);
+        }
+
+        assert_eq!(distribution.most_likely(), 3);
+        assert_eq!(Distribution::from_state(&collapse::<8>(5)).shannon_entropy(), 0.0);
+    }
+}
@@ -0,0 +1,95 @@
+//! Single qubits, as normalized pairs of amplitudes.
+
+use crate::utils::complex_number::Complex;
+use crate::utils::complex_vector::ComplexVector;
+
+/// The state α|0> + β|1> of a single qubit, with |α|² + |β|² = 1.
+///
+/// The derived `==` compares the amplitudes exactly, so it tells apart states
+/// differing only by a global phase, which are physically the same. Use
+/// `is_equivalent` to compare states.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Qubit {
+    alpha: Complex,
+    beta: Complex,
+}
+
+impl Qubit {
+    /// The qubit along α|0> + β|1>, which is normalized. Returns `None` if
+    /// both amplitudes are zero.
+    pub fn new(alpha: Complex, beta: Complex) -> Option<Self> {
+        ComplexVector([alpha, beta]).try_normalize()
+                                    .map(|(ComplexVector([alpha, beta]), _)| Qubit { alpha, beta })
+    }
+
+    /// The state |0>.
+    pub fn zero() -> Self {
+        Qubit { alpha: Complex::ONE, beta: Complex::ZERO }
+    }
+
+    /// The state |1>.
+    pub fn one() -> Self {
+        Qubit { alpha: Complex::ZERO, beta: Complex::ONE }
+    }
+
+    /// The amplitude α of |0>.
+    pub fn alpha(&self) -> Complex {
+        self.alpha
+    }
+
+    /// The amplitude β of |1>.
+    pub fn beta(&self) -> Complex {
+        self.beta
+    }
+
+    /// The same state with the global phase removed: α is made real and
+    /// nonnegative, or β if α is below `epsilon`.
+    pub fn canonical_phase(&self, epsilon: f64) -> Self {
+        let pivot = if self.alpha.abs() < epsilon { self.beta } else { self.alpha };
+        let phase = pivot.unit().conjugate();
+
+        Qubit { alpha: self.alpha * phase, beta: self.beta * phase }
+    }
+
+    /// Whether both qubits are the same state up to a global phase, comparing
+    /// their canonical forms within `epsilon`.
+    pub fn is_equivalent(&self, other: &Qubit, epsilon: f64) -> bool {
+        let lhs = ComplexVector::from(self.canonical_phase(epsilon));
+        let rhs = ComplexVector::from(other.canonical_phase(epsilon));
+
+        lhs.distance_to(&rhs) < epsilon
+    }
+}
+
+impl From<Qubit> for ComplexVector<2> {
+    fn from(qubit: Qubit) -> Self {
+        ComplexVector([qubit.alpha, qubit.beta])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_normalizes() {
+        let qubit = Qubit::new(Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)).unwrap();
+
+        assert!((ComplexVector::from(qubit).norm() - 1.0).abs() < 1e-12);
+        assert_eq!(Qubit::new(Complex::ZERO, Complex::ZERO), None);
+    }
+
+    #[test]
+    fn test_is_equivalent() {
+        let rotated_zero = Qubit::new(Complex::from_angle(1.2), Complex::ZERO).unwrap();
+        assert_ne!(rotated_zero, Qubit::zero());
+        assert!(rotated_zero.is_equivalent(&Qubit::zero(), 1e-9));
+        assert!(!Qubit::zero().is_equivalent(&Qubit::one(), 1e-9));
+
+        let plus = Qubit::new(Complex::ONE, Complex::ONE).unwrap();
+        let rotated_plus = Qubit::new(Complex::I, Complex::I).unwrap();
+        let minus = Qubit::new(Complex::ONE, -Complex::ONE).unwrap();
+        assert!(plus.is_equivalent(&rotated_plus, 1e-9));
+        assert!(!plus.is_equivalent(&minus, 1e-9));
+    }
+}
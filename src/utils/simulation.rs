@@ -0,0 +1,304 @@
+//! Application of gates to the states of multi-qubit registers.
+//!
+//! A register of n qubits has N = 2^n basis states. Qubit 0 is the most
+//! significant bit of the basis index, so on two qubits |01> is the state
+//! with qubit 1 set, at index 1.
+
+use crate::utils::complex_number::Complex;
+use crate::utils::complex_vector::ComplexVector;
+use crate::utils::complex_matrix::ComplexMatrix;
+use crate::utils::bits;
+
+/// Panics unless N is 2^n_qubits and every given qubit is in the register.
+pub(crate) fn check_register<const N: usize>(qubits: &[usize], n_qubits: usize) {
+    if N != 1 << n_qubits {
+        panic!("A register of {} qubits has {} basis states, not {}.", n_qubits, 1 << n_qubits, N);
+    }
+
+    if let Some(qubit) = qubits.iter().find(|&&q| q >= n_qubits) {
+        panic!("Qubit {} is out of a register of {} qubits.", qubit, n_qubits);
+    }
+}
+
+/// The matrix `I ⊗ ... ⊗ gate ⊗ ... ⊗ I` acting as `gate` on the `target`
+/// qubit and as the identity on the rest of a register of `n_qubits`.
+pub fn embed_gate<const N: usize>(gate: &ComplexMatrix<2, 2>, target: usize, n_qubits: usize) -> ComplexMatrix<N, N> {
+    embed(gate, None, target, n_qubits)
+}
+
+/// The matrix acting as `gate` on the `target` qubit of a register of
+/// `n_qubits` when the `control` qubit is set, and as the identity otherwise.
+pub fn embed_controlled<const N: usize>(gate: &ComplexMatrix<2, 2>, control: usize, target: usize, n_qubits: usize) -> ComplexMatrix<N, N> {
+    embed(gate, Some(control), target, n_qubits)
+}
+
+/// Applies a single-qubit `gate` to the `target` qubit of the `state` of a
+/// register of `n_qubits`.
+///
+/// This is the same as multiplying by `embed_gate`, but the gate acts
+/// directly on each pair of amplitudes differing only in the target qubit,
+/// without building the N×N matrix.
+pub fn apply_gate_to_qubit<const N: usize>(state: &ComplexVector<N>, gate: &ComplexMatrix<2, 2>, target: usize, n_qubits: usize) -> ComplexVector<N> {
+    apply_gate(state, gate, None, target, n_qubits)
+}
+
+/// Applies a single-qubit `gate` to the `target` qubit of the `state` of a
+/// register of `n_qubits`, only on the basis states where the `control`
+/// qubit is set.
+///
+/// This is the same as multiplying by `embed_controlled`, without building
+/// the N×N matrix.
+pub fn apply_controlled<const N: usize>(state: &ComplexVector<N>, gate: &ComplexMatrix<2, 2>, control: usize, target: usize, n_qubits: usize) -> ComplexVector<N> {
+    apply_gate(state, gate, Some(control), target, n_qubits)
+}
+
+/// Applies a `gate` on k qubits, with M = 2^k, to the window of contiguous
+/// qubits `start_qubit..start_qubit + k` of the `state` of a register of
+/// `n_qubits`. Within the window the gate follows the usual ordering, so
+/// `start_qubit` is the most significant bit of its basis index.
+///
+/// This is the same as multiplying by `I ⊗ gate ⊗ I`, with identities on the
+/// qubits before and after the window, but the identities are never built:
+/// the gate acts on each group of M amplitudes that differ only inside the
+/// window.
+pub fn apply_gate_window<const N: usize, const M: usize>(state: &ComplexVector<N>, gate: &ComplexMatrix<M, M>, start_qubit: usize, n_qubits: usize) -> ComplexVector<N> {
+    if !M.is_power_of_two() {
+        panic!("A gate on a window of qubits has a power of two rows, not {}.", M);
+    }
+
+    let k = M.trailing_zeros() as usize;
+    if start_qubit + k > n_qubits {
+        panic!("A window of {} qubits from qubit {} is out of a register of {} qubits.", k, start_qubit, n_qubits);
+    }
+    check_register::<N>(&[], n_qubits);
+
+    // Position of the least significant bit of the window.
+    let shift = n_qubits - start_qubit - k;
+    let window_mask = (M - 1) << shift;
+    let mut result = *state;
+
+    for base in (0..N).filter(|&i| i & window_mask == 0) {
+        for r in 0..M {
+            result.0[base | r << shift] = (0..M).map(|c| gate[[r, c]] * state.0[base | c << shift]).sum();
+        }
+    }
+
+    result
+}
+
+/// Builds the matrix of `gate` acting on `target`, conditioned on `control`
+/// if there is one.
+fn embed<const N: usize>(gate: &ComplexMatrix<2, 2>, control: Option<usize>, target: usize, n_qubits: usize) -> ComplexMatrix<N, N> {
+    let (control_mask, target_mask) = masks::<N>(control, target, n_qubits);
+    let mut m = ComplexMatrix::new([[Complex::ZERO; N]; N]);
+
+    // This is the Kronecker product of the identities and the gate: entries
+    // are nonzero only where every other qubit is left unchanged.
+    for r in 0..N {
+        for c in 0..N {
+            if r & !target_mask != c & !target_mask {
+                continue;
+            }
+
+            m[[r, c]] = if c & control_mask == control_mask {
+                gate[[bits::bit(r, target, n_qubits) as usize, bits::bit(c, target, n_qubits) as usize]]
+            } else if r == c {
+                Complex::ONE
+            } else {
+                Complex::ZERO
+            };
+        }
+    }
+
+    m
+}
+
+/// Applies `gate` on `target`, conditioned on `control` if there is one,
+/// acting only on the pairs of amplitudes the gate mixes.
+pub(crate) fn apply_gate<const N: usize>(state: &ComplexVector<N>, gate: &ComplexMatrix<2, 2>, control: Option<usize>, target: usize, n_qubits: usize) -> ComplexVector<N> {
+    let (control_mask, target_mask) = masks::<N>(control, target, n_qubits);
+    let mut result = *state;
+
+    // Visit each pair once, from the index with the target qubit unset.
+    for i in (0..N).filter(|&i| i & target_mask == 0 && i & control_mask == control_mask) {
+        let j = i | target_mask;
+        let (a0, a1) = (state.0[i], state.0[j]);
+
+        result.0[i] = gate[[0, 0]] * a0 + gate[[0, 1]] * a1;
+        result.0[j] = gate[[1, 0]] * a0 + gate[[1, 1]] * a1;
+    }
+
+    result
+}
+
+/// Bit masks of the control (zero if there is none) and target qubits, after
+/// checking they fit in the register.
+fn masks<const N: usize>(control: Option<usize>, target: usize, n_qubits: usize) -> (usize, usize) {
+    check_register::<N>(&[target], n_qubits);
+
+    let control_mask = match control {
+        Some(c) if c == target => panic!("Control and target must be different qubits."),
+        Some(c) => {
+            check_register::<N>(&[c], n_qubits);
+            bits::mask(c, n_qubits)
+        },
+        None => 0,
+    };
+
+    (control_mask, bits::mask(target, n_qubits))
+}
+
+/// Applies the Hadamard gate to every qubit of `state` in place, with the
+/// fast Walsh–Hadamard transform. This takes O(N log N) operations instead of
+/// the O(N²) of multiplying by `hadamard_n`. N must be a power of two, which
+/// is checked on debug builds.
+pub fn fast_hadamard_transform<const N: usize>(state: &mut ComplexVector<N>) {
+    debug_assert!(N.is_power_of_two(), "The Hadamard transform needs a power of two entries, not {}.", N);
+    let amplitudes = &mut state.0;
+    let mut half = 1;
+
+    // Each pass applies the butterfly (a, b) -> (a + b, a - b) on one qubit.
+    while half < N {
+        for block in amplitudes.chunks_mut(2 * half) {
+            let (low, high) = block.split_at_mut(half);
+
+            for (a, b) in low.iter_mut().zip(high.iter_mut()) {
+                let (x, y) = (*a, *b);
+                *a = x + y;
+                *b = x - y;
+            }
+        }
+
+        half *= 2;
+    }
+
+    let scale = Complex::new(1.0 / (N as f64).sqrt(), 0.0);
+    *state *= scale;
+}
+
+/// Applies `gate` to every state in `states`. The gate is borrowed once for
+/// the whole batch, instead of being moved into each product.
+pub fn apply_batch<const N: usize>(gate: &ComplexMatrix<N, N>, states: &[ComplexVector<N>]) -> Vec<ComplexVector<N>> {
+    states.iter().map(|&state| gate * state).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::gates::{hadamard, pauli_x};
+
+    /// Basis state with the given index.
+    fn basis<const N: usize>(index: usize) -> ComplexVector<N> {
+        let mut v = ComplexVector::zeros();
+        v.0[index] = Complex::ONE;
+        v
+    }
+
+    #[test]
+    fn test_apply_gate_to_qubit() {
+        let state = basis::<4>(0b00);
+
+        assert_eq!(apply_gate_to_qubit(&state, &pauli_x(), 1, 2), basis(0b01));
+        assert_eq!(apply_gate_to_qubit(&state, &pauli_x(), 0, 2), basis(0b10));
+        assert_eq!(apply_gate_to_qubit(&basis::<8>(0b101), &pauli_x(), 1, 3), basis(0b111));
+    }
+
+    #[test]
+    fn test_embed_gate() {
+        let x_on_second = ComplexMatrix::<2, 2>::identity().kron(&pauli_x());
+        let h_on_first = hadamard().kron(&ComplexMatrix::<2, 2>::identity());
+
+        assert_eq!(embed_gate::<4>(&pauli_x(), 1, 2), x_on_second);
+        assert_eq!(embed_gate::<4>(&hadamard(), 0, 2), h_on_first);
+    }
+
+    #[test]
+    fn test_apply_gate_to_qubit_matches_embedding() {
+        let state = ComplexVector([Complex::new(0.1, 0.2), Complex::new(-0.3, 0.0), Complex::new(0.0, 0.4),
+                                   Complex::new(0.5, -0.1), Complex::new(0.2, 0.2), Complex::new(0.0, 0.0),
+                                   Complex::new(-0.6, 0.1), Complex::new(0.1, 0.0)]);
+
+        for target in 0..3 {
+            let expected = &embed_gate(&hadamard(), target, 3) * state;
+            let result = apply_gate_to_qubit(&state, &hadamard(), target, 3);
+            assert!(result.distance_to(&expected) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_embed_controlled() {
+        use crate::utils::gates::{controlled, controlled_on};
+
+        assert_eq!(embed_controlled::<4>(&pauli_x(), 0, 1, 2), controlled(pauli_x()));
+        assert_eq!(embed_controlled::<4>(&hadamard(), 1, 0, 2), controlled_on(hadamard(), 1, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_embed_gate_out_of_register() {
+        embed_gate::<4>(&pauli_x(), 2, 2);
+    }
+
+    #[test]
+    fn test_apply_batch() {
+        let states = [basis::<2>(0), basis(1), ComplexVector([Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)])];
+        let results = apply_batch(&hadamard(), &states);
+
+        assert_eq!(results.len(), states.len());
+        for (&state, &result) in states.iter().zip(results.iter()) {
+            assert_eq!(result, hadamard() * state);
+        }
+    }
+
+    #[test]
+    fn test_apply_controlled() {
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let state = ComplexVector([Complex::new(s, 0.0), Complex::ZERO, Complex::new(s, 0.0), Complex::ZERO]);
+        let bell = ComplexVector([Complex::new(s, 0.0), Complex::ZERO, Complex::ZERO, Complex::new(s, 0.0)]);
+
+        assert_eq!(apply_controlled(&state, &pauli_x(), 0, 1, 2), bell);
+        assert_eq!(apply_controlled(&state, &hadamard(), 1, 0, 2), &embed_controlled(&hadamard(), 1, 0, 2) * state);
+    }
+
+    #[test]
+    fn test_fast_hadamard_transform() {
+        use crate::utils::gates::hadamard_n;
+
+        let original = ComplexVector([Complex::new(0.1, 0.2), Complex::new(-0.3, 0.0), Complex::new(0.0, 0.4),
+                                      Complex::new(0.5, -0.1), Complex::new(0.2, 0.2), Complex::new(0.0, 0.0),
+                                      Complex::new(-0.6, 0.1), Complex::new(0.1, 0.0)]);
+        let mut state = original;
+
+        fast_hadamard_transform(&mut state);
+        assert!(state.distance_to(&(hadamard_n::<8>(3) * original)) < 1e-12);
+
+        fast_hadamard_transform(&mut state);
+        assert!(state.distance_to(&original) < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_gate_window() {
+        use crate::utils::gates::controlled;
+
+        let state = ComplexVector([Complex::new(0.1, 0.2), Complex::new(-0.3, 0.0), Complex::new(0.0, 0.4),
+                                   Complex::new(0.5, -0.1), Complex::new(0.2, 0.2), Complex::new(0.0, 0.0),
+                                   Complex::new(-0.6, 0.1), Complex::new(0.1, 0.0)]);
+        let identity = ComplexMatrix::<2, 2>::identity();
+        let cnot = controlled(pauli_x());
+
+        let on_last: ComplexMatrix<8, 8> = identity.kron(&cnot);
+        assert_eq!(apply_gate_window(&state, &cnot, 1, 3), &on_last * state);
+        assert_eq!(on_last, embed_controlled(&pauli_x(), 1, 2, 3));
+
+        let on_first: ComplexMatrix<8, 8> = cnot.kron(&identity);
+        assert_eq!(apply_gate_window(&state, &cnot, 0, 3), &on_first * state);
+
+        assert_eq!(apply_gate_window(&state, &hadamard(), 2, 3), apply_gate_to_qubit(&state, &hadamard(), 2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_gate_window_out_of_register() {
+        apply_gate_window(&basis::<8>(0), &ComplexMatrix::<4, 4>::identity(), 2, 3);
+    }
+}
@@ -0,0 +1,202 @@
+//! Sparse states of multi-qubit registers, storing only the nonzero
+//! amplitudes. They follow the same ordering convention as the dense
+//! simulation: qubit 0 is the most significant bit of the basis index.
+//!
+//! A dense state of n qubits holds 2^n amplitudes, but many algorithms only
+//! ever populate a few basis states, so this allows registers far larger than
+//! a `ComplexVector` could hold.
+
+use std::collections::{BTreeSet, HashMap};
+
+use rand::Rng;
+
+use crate::utils::complex_number::Complex;
+use crate::utils::complex_vector::ComplexVector;
+use crate::utils::complex_matrix::ComplexMatrix;
+use crate::utils::bits;
+
+/// Amplitudes with a magnitude below this are dropped.
+const PRUNE_THRESHOLD: f64 = 1e-12;
+
+/// A state of a register of qubits, as a map from basis indices to their
+/// nonzero amplitudes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseState {
+    n_qubits: usize,
+    amplitudes: HashMap<usize, Complex>,
+}
+
+impl SparseState {
+    /// The basis state `index` of a register of `n_qubits`.
+    pub fn basis(n_qubits: usize, index: usize) -> Self {
+        if n_qubits >= usize::BITS as usize || index >> n_qubits != 0 {
+            panic!("Basis state {} is out of a register of {} qubits.", index, n_qubits);
+        }
+
+        SparseState { n_qubits, amplitudes: HashMap::from([(index, Complex::ONE)]) }
+    }
+
+    /// The state with every qubit of a register of `n_qubits` set to |0>.
+    pub fn zero(n_qubits: usize) -> Self {
+        Self::basis(n_qubits, 0)
+    }
+
+    pub fn n_qubits(&self) -> usize {
+        self.n_qubits
+    }
+
+    /// The amplitude of the basis state `index`, which is zero if it isn't
+    /// stored.
+    pub fn amplitude(&self, index: usize) -> Complex {
+        self.amplitudes.get(&index).copied().unwrap_or(Complex::ZERO)
+    }
+
+    /// Number of stored (nonzero) amplitudes.
+    pub fn nonzero_count(&self) -> usize {
+        self.amplitudes.len()
+    }
+
+    /// Applies a single-qubit `gate` to the `target` qubit.
+    pub fn apply_single_qubit_gate(&mut self, gate: &ComplexMatrix<2, 2>, target: usize) {
+        self.apply(gate, None, target);
+    }
+
+    /// Applies `gate` to the `target` qubit when the `control` qubit is set.
+    pub fn apply_controlled_gate(&mut self, gate: &ComplexMatrix<2, 2>, control: usize, target: usize) {
+        if control == target {
+            panic!("Control and target must be different qubits.");
+        }
+
+        self.apply(gate, Some(control), target);
+    }
+
+    /// Probability of observing each occupied basis state. Basis states not
+    /// in the map have probability zero.
+    pub fn probabilities(&self) -> HashMap<usize, f64> {
+        let total: f64 = self.amplitudes.values().map(|c| c.norm_sqr()).sum();
+
+        self.amplitudes.iter().map(|(&index, c)| (index, c.norm_sqr() / total)).collect()
+    }
+
+    /// Samples the index of the basis state observed when measuring.
+    pub fn measure(&self, rng: &mut impl Rng) -> usize {
+        // Walk the outcomes in index order, so a seeded rng is reproducible
+        // regardless of the order of the map.
+        let mut probabilities: Vec<(usize, f64)> = self.probabilities().into_iter().collect();
+        probabilities.sort_by_key(|&(index, _)| index);
+
+        let sample: f64 = rng.gen();
+        let mut cumulative = 0.0;
+
+        for &(index, probability) in &probabilities {
+            cumulative += probability;
+
+            if sample < cumulative {
+                return index;
+            }
+        }
+
+        // Rounding errors may leave the total just below 1.
+        probabilities.last().map_or(0, |&(index, _)| index)
+    }
+
+    /// The dense vector of the state, where N must be 2^n_qubits.
+    pub fn to_dense<const N: usize>(&self) -> ComplexVector<N> {
+        if N != 1 << self.n_qubits {
+            panic!("A register of {} qubits has {} basis states, not {}.", self.n_qubits, 1 << self.n_qubits, N);
+        }
+
+        let mut v = ComplexVector::zeros();
+
+        for (&index, &amplitude) in &self.amplitudes {
+            v.0[index] = amplitude;
+        }
+
+        v
+    }
+
+    /// Bit mask of `qubit`, after checking it is in the register.
+    fn mask(&self, qubit: usize) -> usize {
+        if qubit >= self.n_qubits {
+            panic!("Qubit {} is out of a register of {} qubits.", qubit, self.n_qubits);
+        }
+
+        bits::mask(qubit, self.n_qubits)
+    }
+
+    /// Applies `gate` on `target`, conditioned on `control` if there is one,
+    /// visiting only the pairs of amplitudes that have an occupied index.
+    fn apply(&mut self, gate: &ComplexMatrix<2, 2>, control: Option<usize>, target: usize) {
+        let target_mask = self.mask(target);
+        let control_mask = control.map_or(0, |c| self.mask(c));
+
+        // Each pair is identified by its index with the target qubit unset.
+        let pairs: BTreeSet<usize> = self.amplitudes.keys()
+                                         .filter(|&&i| i & control_mask == control_mask)
+                                         .map(|&i| i & !target_mask)
+                                         .collect();
+
+        for i in pairs {
+            let j = i | target_mask;
+            let (a0, a1) = (self.amplitude(i), self.amplitude(j));
+
+            self.set(i, gate[[0, 0]] * a0 + gate[[0, 1]] * a1);
+            self.set(j, gate[[1, 0]] * a0 + gate[[1, 1]] * a1);
+        }
+    }
+
+    /// Stores `amplitude` at `index`, or drops it if it is negligible.
+    fn set(&mut self, index: usize, amplitude: Complex) {
+        if amplitude.abs() < PRUNE_THRESHOLD {
+            self.amplitudes.remove(&index);
+        } else {
+            self.amplitudes.insert(index, amplitude);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use crate::utils::gates::{hadamard, pauli_x};
+    use crate::utils::simulation::{apply_gate_to_qubit, embed_controlled};
+
+    #[test]
+    fn test_bell_matches_dense() {
+        let mut sparse = SparseState::zero(2);
+        sparse.apply_single_qubit_gate(&hadamard(), 0);
+        sparse.apply_controlled_gate(&pauli_x(), 0, 1);
+
+        let dense = apply_gate_to_qubit(&SparseState::zero(2).to_dense::<4>(), &hadamard(), 0, 2);
+        let dense = &embed_controlled(&pauli_x(), 0, 1, 2) * dense;
+
+        assert_eq!(sparse.nonzero_count(), 2);
+        for index in [0b00, 0b11] {
+            assert_eq!(sparse.amplitude(index), dense.0[index]);
+        }
+        assert_eq!(sparse.to_dense::<4>(), dense);
+    }
+
+    #[test]
+    fn test_pruning() {
+        let mut state = SparseState::zero(40);
+        state.apply_single_qubit_gate(&hadamard(), 17);
+        assert_eq!(state.nonzero_count(), 2);
+
+        state.apply_single_qubit_gate(&hadamard(), 17);
+        assert_eq!(state.nonzero_count(), 1);
+        assert!((state.amplitude(0) - Complex::ONE).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut state = SparseState::basis(50, 1 << 49);
+        state.apply_single_qubit_gate(&pauli_x(), 0);
+        state.apply_single_qubit_gate(&pauli_x(), 49);
+
+        assert_eq!(state.probabilities(), HashMap::from([(1, 1.0)]));
+        assert_eq!(state.measure(&mut rng), 1);
+    }
+}
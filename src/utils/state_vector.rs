@@ -0,0 +1,119 @@
+//! Quantum states, as complex vectors that are kept normalized.
+
+use std::ops::Deref;
+
+use rand::Rng;
+
+use crate::utils::complex_number::Complex;
+use crate::utils::complex_vector::ComplexVector;
+use crate::utils::complex_matrix::ComplexMatrix;
+use crate::utils::measurement;
+use crate::utils::simulation;
+
+/// A state of a quantum system with N basis states, i.e. a unit vector.
+///
+/// It dereferences to the inner `ComplexVector` for read access. Applying a
+/// gate renormalizes the result, so every `StateVector` is a unit vector.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StateVector<const N: usize>(ComplexVector<N>);
+
+impl<const N: usize> StateVector<N> {
+    /// The state along `vector`, which is normalized. Returns `None` for the
+    /// zero vector, which is not a state.
+    pub fn new(vector: ComplexVector<N>) -> Option<Self> {
+        vector.try_normalize().map(|(unit, _)| StateVector(unit))
+    }
+
+    /// The amplitude of each basis state.
+    pub fn amplitudes(&self) -> &[Complex; N] {
+        &self.0.0
+    }
+
+    /// Probability of observing each basis state.
+    pub fn probabilities(&self) -> [f64; N] {
+        measurement::probabilities(&self.0)
+    }
+
+    /// Samples the index of the basis state observed when measuring.
+    pub fn measure(&self, rng: &mut impl Rng) -> usize {
+        measurement::measure(&self.0, rng)
+    }
+
+    /// The state after applying `gate`. The result is renormalized, like in
+    /// `new`, so a gate that isn't unitary still gives a unit vector. Returns
+    /// `None` if the gate maps the state to zero.
+    pub fn apply(&self, gate: &ComplexMatrix<N, N>) -> Option<Self> {
+        Self::new(gate * self.0)
+    }
+
+    /// The state after applying a single-qubit `gate` to the `target` qubit of
+    /// a register of `n_qubits`, renormalized as in `apply`.
+    pub fn apply_to_qubit(&self, gate: &ComplexMatrix<2, 2>, target: usize, n_qubits: usize) -> Option<Self> {
+        Self::new(simulation::apply_gate_to_qubit(&self.0, gate, target, n_qubits))
+    }
+
+    /// The underlying unit vector.
+    pub fn into_inner(self) -> ComplexVector<N> {
+        self.0
+    }
+}
+
+impl<const N: usize> Deref for StateVector<N> {
+    type Target = ComplexVector<N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use crate::utils::gates::{hadamard, pauli_x};
+
+    #[test]
+    fn test_new_normalizes() {
+        let state = StateVector::new(ComplexVector([Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)])).unwrap();
+
+        assert!((state.norm() - 1.0).abs() < 1e-12);
+        assert!(state.distance_to(&ComplexVector([Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)])) < 1e-12);
+        assert_eq!(state.amplitudes(), &state.0.0);
+        assert_eq!(StateVector::new(ComplexVector::<2>::zeros()), None);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let one = StateVector::new(ComplexVector([Complex::ZERO, Complex::new(0.0, 5.0)])).unwrap();
+
+        assert_eq!(one.probabilities(), [0.0, 1.0]);
+        for _ in 0..10 {
+            assert_eq!(one.measure(&mut rng), 1);
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let zero = StateVector::new(ComplexVector([Complex::ONE, Complex::ZERO, Complex::ZERO, Complex::ZERO])).unwrap();
+        let flipped = zero.apply_to_qubit(&pauli_x(), 1, 2).unwrap();
+        assert_eq!(flipped.into_inner(), ComplexVector([Complex::ZERO, Complex::ONE, Complex::ZERO, Complex::ZERO]));
+
+        let plus = StateVector::new(ComplexVector([Complex::ONE, Complex::ZERO])).unwrap().apply(&hadamard()).unwrap();
+        let [p0, p1] = plus.probabilities();
+        assert!((p0 - 0.5).abs() < 1e-12 && (p1 - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_non_unitary() {
+        let plus = StateVector::new(ComplexVector([Complex::ONE, Complex::ONE])).unwrap();
+        let projector_zero = ComplexMatrix::from_diagonal([Complex::ONE, Complex::ZERO]);
+        let projector_minus = ComplexMatrix::new([[Complex::new(0.5, 0.0), Complex::new(-0.5, 0.0)],
+                                                  [Complex::new(-0.5, 0.0), Complex::new(0.5, 0.0)]]);
+
+        let zero = plus.apply(&projector_zero).unwrap();
+        assert!(zero.distance_to(&ComplexVector([Complex::ONE, Complex::ZERO])) < 1e-12);
+        assert!(plus.apply_to_qubit(&(projector_zero * Complex::new(3.0, 0.0)), 0, 1).unwrap().distance_to(&zero) < 1e-12);
+        assert_eq!(plus.apply(&projector_minus), None);
+    }
+}
@@ -0,0 +1,74 @@
+use proptest::prelude::*;
+
+use quantum_computing_studies::utils::complex_number::Complex;
+use quantum_computing_studies::utils::complex_vector::ComplexVector;
+use quantum_computing_studies::utils::complex_matrix::ComplexMatrix;
+
+/// Tolerance for the comparisons, since the laws only hold up to rounding errors.
+const EPSILON: f64 = 1e-9;
+
+/// Complex numbers with both parts bounded, to keep rounding errors small.
+fn complex() -> impl Strategy<Value = Complex> {
+    (-100.0..100.0f64, -100.0..100.0f64).prop_map(|(r, i)| Complex::new(r, i))
+}
+
+fn vector() -> impl Strategy<Value = ComplexVector<3>> {
+    [complex(), complex(), complex()].prop_map(ComplexVector)
+}
+
+/// Entries of a 2x2 matrix. Matrices are moved by the operators, so the tests
+/// build them from these arrays as many times as needed.
+fn matrix_entries() -> impl Strategy<Value = [[Complex; 2]; 2]> {
+    [[complex(), complex()], [complex(), complex()]]
+}
+
+fn close(c1: Complex, c2: Complex) -> bool {
+    (c1 - c2).abs() < EPSILON
+}
+
+proptest! {
+    #[test]
+    fn complex_addition_commutes(c1 in complex(), c2 in complex()) {
+        prop_assert!(close(c1 + c2, c2 + c1));
+    }
+
+    #[test]
+    fn vector_addition_commutes(v1 in vector(), v2 in vector()) {
+        let (ComplexVector(lhs), ComplexVector(rhs)) = (v1 + v2, v2 + v1);
+        prop_assert!(lhs.iter().zip(rhs.iter()).all(|(&x, &y)| close(x, y)));
+    }
+
+    #[test]
+    fn matrix_addition_commutes(a1 in matrix_entries(), a2 in matrix_entries()) {
+        let lhs = ComplexMatrix::new(a1) + ComplexMatrix::new(a2);
+        let rhs = ComplexMatrix::new(a2) + ComplexMatrix::new(a1);
+        for r in 0..2 {
+            for c in 0..2 {
+                prop_assert!(close(lhs[[r, c]], rhs[[r, c]]));
+            }
+        }
+    }
+
+    #[test]
+    fn scalar_distributes_over_vector_addition(c in complex(), v1 in vector(), v2 in vector()) {
+        let ComplexVector(lhs) = (v1 + v2) * c;
+        let ComplexVector(rhs) = v1 * c + v2 * c;
+        // Products of bounded numbers are larger, so scale the tolerance.
+        prop_assert!(lhs.iter().zip(rhs.iter()).all(|(&x, &y)| (x - y).abs() < EPSILON * 1e4));
+    }
+
+    #[test]
+    fn vector_plus_inverse_is_zero(v in vector()) {
+        prop_assert!((v + -v).norm() < EPSILON);
+    }
+
+    #[test]
+    fn matrix_plus_inverse_is_zero(a in matrix_entries()) {
+        let zero = ComplexMatrix::new(a) + -ComplexMatrix::new(a);
+        for r in 0..2 {
+            for c in 0..2 {
+                prop_assert!(close(zero[[r, c]], Complex::ZERO));
+            }
+        }
+    }
+}